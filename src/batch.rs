@@ -0,0 +1,50 @@
+use std::{num::NonZeroUsize, thread, time::Duration};
+
+use crate::{Error, Parser};
+
+impl Parser {
+    /// Parses many strings in parallel, returning the results in the same
+    /// order as `inputs`.
+    ///
+    /// The inputs are split into contiguous chunks, one per available CPU,
+    /// and each chunk is parsed on its own thread.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::Parser;
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    /// let results = parser.parse_batch(&["1s", "2 min", "3 x"]);
+    /// assert_eq!(results[0], Ok(Duration::from_secs(1)));
+    /// assert_eq!(results[1], Ok(Duration::from_secs(120)));
+    /// assert!(results[2].is_err());
+    /// ```
+    pub fn parse_batch(&self, inputs: &[&str]) -> Vec<Result<Duration, Error>> {
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let chunk_size = inputs.len().div_ceil(threads).max(1);
+
+        if chunk_size == inputs.len() {
+            return inputs.iter().map(|input| self.parse(input)).collect();
+        }
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = inputs
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|input| self.parse(input))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        })
+    }
+}
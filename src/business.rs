@@ -0,0 +1,190 @@
+use std::time::Duration;
+
+use crate::{Error, Parser, calendar, lexer::TokenKind};
+
+/// A working schedule used to resolve business-time units.
+///
+/// When set in [`ParserOptions::business`](crate::ParserOptions::business),
+/// the parser recognizes the following units:
+/// - `workday(s)`, `business day(s)`: one working day
+/// - `workweek(s)`, `business week(s)`: `days_per_week` working days
+///
+/// [`Parser::parse`] resolves a working day to `workday` of working time,
+/// so with the default schedule "2 business days" is 16 hours.
+///
+/// [`Parser::parse_from`] instead counts working days on the calendar,
+/// skipping weekends and holidays, and returns the elapsed time until the
+/// same time of day on the resulting date.
+///
+/// ## Example
+/// ```rust
+/// use durstr::{BusinessSchedule, Parser, ParserOptions};
+/// use std::time::Duration;
+///
+/// let parser = Parser::new(ParserOptions {
+///     business: Some(BusinessSchedule::default()),
+///     ..Default::default()
+/// });
+///
+/// let d = parser.parse("1 workweek");
+/// assert_eq!(d, Ok(Duration::from_secs(3600) * 40));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BusinessSchedule {
+    /// The amount of working time in a working day.
+    pub workday: Duration,
+    /// The number of working days in a week, starting on Monday (clamped to 1..=7).
+    pub days_per_week: u32,
+    /// Returns whether the given UTC date `(year, month, day)` is a holiday.
+    ///
+    /// Only used by [`Parser::parse_from`], which fails with
+    /// [`Error::NoWorkingDay`] if there is no working day for a whole year.
+    pub is_holiday: Option<fn(i64, u32, u32) -> bool>,
+}
+
+impl Default for BusinessSchedule {
+    /// An 8 hour workday, Monday through Friday, with no holidays.
+    fn default() -> Self {
+        BusinessSchedule {
+            workday: Duration::from_secs(8 * 3600),
+            days_per_week: 5,
+            is_holiday: None,
+        }
+    }
+}
+
+impl BusinessSchedule {
+    fn days_per_week(&self) -> u32 {
+        self.days_per_week.clamp(1, 7)
+    }
+
+    fn is_working_day(&self, days: i64) -> bool {
+        // 1970-01-01 was a Thursday, Monday is 0
+        let weekday = (days + 3).rem_euclid(7) as u32;
+        if weekday >= self.days_per_week() {
+            return false;
+        }
+
+        match self.is_holiday {
+            Some(is_holiday) => {
+                let (y, m, d) = calendar::civil_from_days(days);
+                !is_holiday(y, m, d)
+            }
+            None => true,
+        }
+    }
+
+    /// Returns the first working day after the date `days` since the unix
+    /// epoch.
+    fn next_working_day(&self, days: i64) -> Result<i64, Error> {
+        let mut days = days;
+        let mut run = 0;
+        loop {
+            days = days.checked_add(1).ok_or(Error::Overflow)?;
+            if self.is_working_day(days) {
+                return Ok(days);
+            }
+            // a week always has a working day, unless every day of it is a
+            // holiday
+            run += 1;
+            if run > MAX_HOLIDAY_RUN {
+                return Err(Error::NoWorkingDay);
+            }
+        }
+    }
+
+    /// Returns the date `n` working days after the date `days` since the unix
+    /// epoch.
+    ///
+    /// Without holidays, whole weeks are skipped at once from the first
+    /// working day. With holidays, every day has to be checked, so the search
+    /// gives up after `MAX_HOLIDAY_SCAN` days.
+    pub(crate) fn add_working_days(&self, days: i64, n: u64) -> Result<i64, Error> {
+        let per_week = u64::from(self.days_per_week());
+        let mut days = days;
+        let (weeks, rest) = match self.is_holiday {
+            Some(_) => (0, n),
+            // a week later is the same number of working days ahead only
+            // when starting from a working day
+            None if n > 0 => {
+                days = self.next_working_day(days)?;
+                ((n - 1) / per_week, (n - 1) % per_week)
+            }
+            None => (0, 0),
+        };
+
+        days = i64::try_from(weeks)
+            .ok()
+            .and_then(|weeks| weeks.checked_mul(7))
+            .and_then(|skipped| days.checked_add(skipped))
+            .ok_or(Error::Overflow)?;
+        let limit = days.saturating_add(MAX_HOLIDAY_SCAN);
+
+        for _ in 0..rest {
+            days = self.next_working_day(days)?;
+            if self.is_holiday.is_some() && days > limit {
+                return Err(Error::Overflow);
+            }
+        }
+
+        Ok(days)
+    }
+}
+
+/// How many consecutive days off are searched for a working day before
+/// giving up, as `is_holiday` may mark every day as a holiday.
+const MAX_HOLIDAY_RUN: u32 = 366;
+
+/// How many days ahead working days are counted when there are holidays,
+/// about 10,000 years.
+const MAX_HOLIDAY_SCAN: i64 = 3_652_425;
+
+impl Parser {
+    /// Returns the number of working days in a business-time unit, if
+    /// business units are enabled.
+    pub(crate) fn business_unit_days(&self, unit: &str) -> Option<u32> {
+        let schedule = self.options.business.as_ref()?;
+        let unit = self.normalize_unit(unit);
+        let words: Vec<&str> = unit.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["workday" | "workdays"] | ["business", "day" | "days"] => Some(1),
+            ["workweek" | "workweeks"] | ["business", "week" | "weeks"] => {
+                Some(schedule.days_per_week())
+            }
+            _ => None,
+        }
+    }
+
+    /// Joins "business" with the unit following it, so that "business days"
+    /// is a single unit.
+    pub(crate) fn join_business_units<'a>(
+        &self,
+        source: &'a str,
+        tokens: Vec<TokenKind<'a>>,
+    ) -> Vec<TokenKind<'a>> {
+        let offset = |s: &str| s.as_ptr() as usize - source.as_ptr() as usize;
+        let mut joined = Vec::with_capacity(tokens.len());
+        let mut tokens = tokens.into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            match (token, tokens.peek()) {
+                // only whitespace may be between the words, so a comma still
+                // separates them
+                (TokenKind::Unit(u), Some(&TokenKind::Unit(next)))
+                    if self.normalize_unit(u) == "business"
+                        && source[offset(u) + u.len()..offset(next)]
+                            .chars()
+                            .all(char::is_whitespace) =>
+                {
+                    tokens.next();
+                    let end = offset(next) + next.len();
+                    joined.push(TokenKind::Unit(&source[offset(u)..end]));
+                }
+                (token, _) => joined.push(token),
+            }
+        }
+
+        joined
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use crate::{Error, Parser};
+
+/// A [`Parser`] that remembers the results for recently parsed strings.
+///
+/// Useful for workloads that parse the same handful of strings over and over,
+/// such as configuration values read on every request. The cache holds up to
+/// `capacity` results, evicting the least recently used one when full. It is
+/// meant to be small, as eviction scans every entry.
+///
+/// ## Example
+/// ```rust
+/// use durstr::{CachedParser, Parser};
+/// use std::time::Duration;
+///
+/// let parser = CachedParser::new(Parser::default(), 16);
+///
+/// let dur = parser.parse("1 minute, 2 seconds");
+/// assert_eq!(dur, Ok(Duration::from_secs(62)));
+///
+/// // served from the cache
+/// let dur = parser.parse("1 minute, 2 seconds");
+/// assert_eq!(dur, Ok(Duration::from_secs(62)));
+/// ```
+pub struct CachedParser {
+    parser: Parser,
+    capacity: usize,
+    cache: Mutex<Cache>,
+}
+
+#[derive(Default)]
+struct Cache {
+    entries: HashMap<String, (Result<Duration, Error>, u64)>,
+    clock: u64,
+}
+
+impl CachedParser {
+    /// Create a new [`CachedParser`] caching up to `capacity` results of `parser`.
+    pub fn new(parser: Parser, capacity: usize) -> Self {
+        CachedParser {
+            parser,
+            capacity,
+            cache: Mutex::default(),
+        }
+    }
+
+    /// Parses a string into a `Duration`, like [`Parser::parse`], reusing the
+    /// cached result if the same string was parsed recently.
+    pub fn parse(&self, input: &str) -> Result<Duration, Error> {
+        if self.capacity == 0 {
+            return self.parser.parse(input);
+        }
+
+        if let Some(result) = self.lock().get(input) {
+            return result;
+        }
+
+        // parse without holding the lock, so other threads aren't blocked
+        let result = self.parser.parse(input);
+        self.lock().insert(input, result.clone(), self.capacity);
+
+        result
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Cache> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if there are no cached results.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Cache {
+    /// Returns the cached result for `input`, marking it as recently used.
+    fn get(&mut self, input: &str) -> Option<Result<Duration, Error>> {
+        self.clock += 1;
+        let (result, last_used) = self.entries.get_mut(input)?;
+        *last_used = self.clock;
+        Some(result.clone())
+    }
+
+    /// Caches `result` for `input`, evicting the least recently used result
+    /// if the cache is full.
+    fn insert(&mut self, input: &str, result: Result<Duration, Error>, capacity: usize) {
+        self.clock += 1;
+        let now = self.clock;
+
+        // another thread may have cached it while this one was parsing
+        if let Some((_, last_used)) = self.entries.get_mut(input) {
+            *last_used = now;
+            return;
+        }
+
+        if self.entries.len() >= capacity {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(k, _)| k.clone());
+            if let Some(lru) = lru {
+                self.entries.remove(&lru);
+            }
+        }
+        self.entries.insert(input.to_owned(), (result, now));
+    }
+}
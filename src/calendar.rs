@@ -0,0 +1,158 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Error, Parser, add_durations, lexer::TokenKind, whole_quantity};
+
+const SECS_PER_DAY: i64 = 86400;
+
+impl Parser {
+    /// Parses a string into a `Duration`, resolving calendar units relative
+    /// to `anchor`.
+    ///
+    /// Months (`mo`, `mon(s)`, `month(s)`) and years (`y`, `yr(s)`,
+    /// `year(s)`) are applied to the anchor's UTC date, so their length
+    /// depends on the month and on leap years. When the target month is
+    /// shorter than the anchor's day, the day is clamped to the end of that
+    /// month (e.g. January 31 + 1 month is February 28 or 29). All other units
+    /// are resolved with the parser's units, as in [`Parser::parse`].
+    ///
+    /// Business-time units count working days on the calendar, see
+    /// `BusinessSchedule`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::Parser;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// // 2024-02-01T00:00:00Z
+    /// let anchor = UNIX_EPOCH + Duration::from_secs(19754 * 86400);
+    ///
+    /// let parser = Parser::default();
+    /// let dur = parser.parse_from("1 month", anchor);
+    /// assert_eq!(dur, Ok(Duration::from_secs(29 * 86400)));
+    /// ```
+    pub fn parse_from(&self, input: &str, anchor: SystemTime) -> Result<Duration, Error> {
+        let tokens = self.scan(input)?;
+        self.parse_tokens_from(tokens, anchor)
+    }
+
+    fn parse_tokens_from(
+        &self,
+        tokens: Vec<TokenKind>,
+        anchor: SystemTime,
+    ) -> Result<Duration, Error> {
+        let mut months = 0u64;
+        #[cfg(feature = "business")]
+        let mut working_days = 0u64;
+        let mut fixed = Duration::ZERO;
+
+        self.for_each_clause(tokens, |num, unit| {
+            if let Some(m) = self.calendar_unit_months(unit) {
+                months = whole_quantity(num)?
+                    .checked_mul(m)
+                    .and_then(|n| n.checked_add(months))
+                    .ok_or(Error::Overflow)?;
+                return Ok(());
+            }
+
+            #[cfg(feature = "business")]
+            if let Some(d) = self.business_unit_days(unit) {
+                working_days = whole_quantity(num)?
+                    .checked_mul(u64::from(d))
+                    .and_then(|n| n.checked_add(working_days))
+                    .ok_or(Error::Overflow)?;
+                return Ok(());
+            }
+
+            fixed = add_durations(fixed, self.clause_duration(num, unit)?)?;
+            Ok(())
+        })?;
+
+        let start = unix_days(anchor);
+        let months = i64::try_from(months).map_err(|_| Error::Overflow)?;
+        let end = add_months(start, months).ok_or(Error::Overflow)?;
+        #[cfg(feature = "business")]
+        let end = match &self.options.business {
+            Some(schedule) => schedule.add_working_days(end, working_days)?,
+            None => end,
+        };
+
+        let secs = end
+            .checked_sub(start)
+            .and_then(|days| u64::try_from(days).ok())
+            .and_then(|days| days.checked_mul(SECS_PER_DAY as u64))
+            .ok_or(Error::Overflow)?;
+        add_durations(Duration::from_secs(secs), fixed)
+    }
+
+    fn calendar_unit_months(&self, unit: &str) -> Option<u64> {
+        match self.normalize_unit(unit).as_ref() {
+            "mo" | "mos" | "mon" | "mons" | "month" | "months" => Some(1),
+            "y" | "yr" | "yrs" | "year" | "years" => Some(12),
+            _ => None,
+        }
+    }
+}
+
+/// Days since the unix epoch of the UTC date containing `t`.
+pub(crate) fn unix_days(t: SystemTime) -> i64 {
+    let secs = match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs() as i64,
+        Err(e) => {
+            let d = e.duration();
+            -(d.as_secs() as i64) - i64::from(d.subsec_nanos() > 0)
+        }
+    };
+    secs.div_euclid(SECS_PER_DAY)
+}
+
+/// Adds `months` to the date `days` since the unix epoch, clamping the day to
+/// the end of the resulting month. Negative `months` go back in time.
+///
+/// Returns `None` if the resulting date is out of range.
+pub(crate) fn add_months(days: i64, months: i64) -> Option<i64> {
+    let (y, m, d) = civil_from_days(days);
+    let total = y
+        .checked_mul(12)?
+        .checked_add(m as i64 - 1)?
+        .checked_add(months)?;
+    let (y, m) = (total.div_euclid(12), total.rem_euclid(12) as u32 + 1);
+    days_from_civil(y, m, d.min(days_in_month(y, m)))
+}
+
+fn is_leap_year(y: i64) -> bool {
+    (y % 4 == 0 && y % 100 != 0) || y % 400 == 0
+}
+
+pub(crate) fn days_in_month(y: i64, m: u32) -> u32 {
+    match m {
+        2 if is_leap_year(y) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since the unix epoch of a date, or `None` if out of range.
+// See http://howardhinnant.github.io/date_algorithms.html
+pub(crate) fn days_from_civil(y: i64, m: u32, d: u32) -> Option<i64> {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = (m as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era.checked_mul(146097)?.checked_add(doe - 719468)
+}
+
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    (y, m, d)
+}
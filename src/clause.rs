@@ -0,0 +1,116 @@
+use std::{ops::Range, time::Duration};
+
+use crate::{
+    Error, Parser, Unit, add_durations,
+    lexer::{Lexer, Quantity},
+};
+
+/// A number followed by a unit in a duration string, such as "30 mins" in
+/// "1h 30 mins", along with what it resolved to.
+///
+/// Returned by [`Parser::parse_clauses`], and the components of a
+/// [`Cst`](crate::Cst).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Clause<'a> {
+    value: Quantity,
+    unit: Option<Unit>,
+    duration: Duration,
+    span: Range<usize>,
+    unit_span: Range<usize>,
+    text: &'a str,
+}
+
+impl<'a> Clause<'a> {
+    /// The number of the clause.
+    pub fn value(&self) -> Quantity {
+        self.value
+    }
+
+    /// The unit the clause's unit resolved to, or `None` if it is not one of
+    /// the units of [`Unit`], like a custom "days" unit. Custom units are
+    /// never resolved to a [`Unit`], even if they have the same length.
+    pub fn unit(&self) -> Option<Unit> {
+        self.unit
+    }
+
+    /// The unit as written.
+    pub fn unit_text(&self) -> &'a str {
+        &self.text[self.unit_span.start - self.span.start..]
+    }
+
+    /// The byte range of the unit in the input.
+    pub fn unit_span(&self) -> Range<usize> {
+        self.unit_span.clone()
+    }
+
+    /// The duration of the clause.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The byte range of the clause in the input.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The clause as written, from the start of the number to the end of the
+    /// unit.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+}
+
+impl Parser {
+    /// Parses a string into its [`Clause`]s, for tools that highlight or
+    /// edit the components of an input one by one.
+    ///
+    /// As with [`Parser::parse_cst`], the input must be a plain list of
+    /// components: a leading "every", quantifiers and business units are not
+    /// supported.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::{Parser, Unit};
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    /// let clauses = parser.parse_clauses("1h, 30 mins").unwrap();
+    ///
+    /// assert_eq!(clauses[1].unit(), Some(Unit::Minute));
+    /// assert_eq!(clauses[1].duration(), Duration::from_secs(1800));
+    /// assert_eq!(clauses[1].span(), 4..11);
+    /// assert_eq!(clauses[1].text(), "30 mins");
+    /// ```
+    pub fn parse_clauses<'a>(&self, input: &'a str) -> Result<Vec<Clause<'a>>, Error> {
+        let tokens = Lexer::new(input).collect::<Result<Vec<_>, _>>()?;
+        let mut resolved = vec![];
+
+        let kinds = tokens.iter().map(|t| t.kind).collect();
+        self.for_each_clause(kinds, |value, unit| {
+            let duration = self.clause_duration(value, unit)?;
+            resolved.push((value, self.get_standard_unit(unit)?, duration));
+            Ok(())
+        })?;
+        resolved
+            .iter()
+            .try_fold(Duration::ZERO, |a, &(_, _, b)| add_durations(a, b))?;
+
+        let clauses = tokens
+            .chunks(2)
+            .zip(resolved)
+            .map(|(pair, (value, unit, duration))| {
+                let span = pair[0].span.start..pair[1].span.end;
+                Clause {
+                    value,
+                    unit,
+                    duration,
+                    text: &input[span.clone()],
+                    span,
+                    unit_span: pair[1].span.clone(),
+                }
+            })
+            .collect();
+
+        Ok(clauses)
+    }
+}
@@ -0,0 +1,67 @@
+// Shared by several optional features, not all of which may be enabled.
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use crate::Error;
+
+/// Parses a clock-style time, `h:mm[:ss[.f]]`, into a `Duration`.
+///
+/// Minutes and seconds must be at most 59, hours at most `max_hours`, and
+/// the fraction at most `max_fraction_digits` long (up to 9).
+pub(crate) fn parse_clock(
+    s: &str,
+    max_hours: u64,
+    max_fraction_digits: usize,
+) -> Result<Duration, Error> {
+    let (hours, rest) = s.split_once(':').ok_or(Error::ExpectedNumber)?;
+    let (minutes, seconds) = match rest.split_once(':') {
+        Some((m, s)) => (m, Some(s)),
+        None => (rest, None),
+    };
+    let (seconds, fraction) = match seconds.map(|s| s.split_once('.')) {
+        Some(Some((s, f))) => (Some(s), Some(f)),
+        Some(None) => (seconds, None),
+        None => (None, None),
+    };
+
+    let hours = parse_component(hours, max_hours)?;
+    let minutes = parse_component(minutes, 59)?;
+    let seconds = match seconds {
+        Some(s) => parse_component(s, 59)?,
+        None => 0,
+    };
+    let nanos = match fraction {
+        Some(f) if f.len() > max_fraction_digits.min(9) => {
+            return Err(Error::OutOfRange(f.to_owned()));
+        }
+        Some(f) => parse_digits(f)? as u32 * 10u32.pow(9 - f.len() as u32),
+        None => 0,
+    };
+
+    let secs = hours
+        .checked_mul(3600)
+        .and_then(|h| h.checked_add(minutes * 60 + seconds))
+        .ok_or_else(|| Error::OutOfRange(s.to_owned()))?;
+    Ok(Duration::new(secs, nanos))
+}
+
+/// Parses a number that must be at most `max`.
+pub(crate) fn parse_component(s: &str, max: u64) -> Result<u64, Error> {
+    let n = parse_digits(s)?;
+    if n > max {
+        return Err(Error::OutOfRange(s.to_owned()));
+    }
+    Ok(n)
+}
+
+/// Parses a number made up only of ASCII digits.
+pub(crate) fn parse_digits(s: &str) -> Result<u64, Error> {
+    if let Some(c) = s.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(Error::UnexpectedChar(c));
+    }
+    s.parse().map_err(|_| match s {
+        "" => Error::ExpectedNumber,
+        _ => Error::OutOfRange(s.to_owned()),
+    })
+}
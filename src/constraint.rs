@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use crate::{Error, Parser};
+
+/// A comparison operator in a [`DurationPredicate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+    /// `=` or `==`
+    Equal,
+}
+
+impl Comparison {
+    fn eval(self, a: Duration, b: Duration) -> bool {
+        match self {
+            Comparison::Less => a < b,
+            Comparison::LessOrEqual => a <= b,
+            Comparison::Greater => a > b,
+            Comparison::GreaterOrEqual => a >= b,
+            Comparison::Equal => a == b,
+        }
+    }
+}
+
+/// A condition on durations, such as "> 1s and < 30s".
+///
+/// Returned by [`Parser::parse_constraint`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DurationPredicate {
+    comparisons: Vec<(Comparison, Duration)>,
+}
+
+impl DurationPredicate {
+    /// The comparisons that make up the predicate, all of which must hold.
+    pub fn comparisons(&self) -> &[(Comparison, Duration)] {
+        &self.comparisons
+    }
+
+    /// Returns whether `d` satisfies every comparison of the predicate.
+    pub fn eval(&self, d: Duration) -> bool {
+        self.comparisons
+            .iter()
+            .all(|&(op, bound)| op.eval(d, bound))
+    }
+}
+
+impl Parser {
+    /// Parses a constraint on durations, made of comparisons joined by "and"
+    /// (">= 5 min", "> 1s and < 30s").
+    ///
+    /// Each comparison is an operator (`<`, `<=`, `>`, `>=`, `=` or `==`)
+    /// followed by a duration.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::Parser;
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    /// let predicate = parser.parse_constraint("> 1s and <= 30s").unwrap();
+    /// assert!(predicate.eval(Duration::from_secs(30)));
+    /// assert!(!predicate.eval(Duration::from_secs(1)));
+    /// ```
+    pub fn parse_constraint(&self, input: &str) -> Result<DurationPredicate, Error> {
+        let mut comparisons = vec![];
+        let mut words = input.split_whitespace().peekable();
+
+        loop {
+            let mut clause = String::new();
+            while let Some(word) = words.next_if(|w| self.normalize_unit(w) != "and") {
+                clause.push_str(word);
+                clause.push(' ');
+            }
+            comparisons.push(self.parse_comparison(&clause)?);
+
+            // skip the "and"
+            if words.next().is_none() {
+                break;
+            }
+        }
+
+        Ok(DurationPredicate { comparisons })
+    }
+
+    fn parse_comparison(&self, clause: &str) -> Result<(Comparison, Duration), Error> {
+        const OPERATORS: [(&str, Comparison); 6] = [
+            ("<=", Comparison::LessOrEqual),
+            (">=", Comparison::GreaterOrEqual),
+            ("==", Comparison::Equal),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+            ("=", Comparison::Equal),
+        ];
+
+        let (op, rest) = OPERATORS
+            .into_iter()
+            .find_map(|(s, op)| clause.strip_prefix(s).map(|rest| (op, rest)))
+            .ok_or(Error::ExpectedComparison)?;
+
+        if rest.trim().is_empty() {
+            return Err(Error::ExpectedNumber);
+        }
+
+        Ok((op, self.parse(rest)?))
+    }
+}
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use crate::{Error, Parser};
+
+/// A type that can be created from a parsed `Duration`.
+///
+/// Implement this for your own types to parse into them directly with
+/// [`Parser::parse_into`].
+///
+/// ## Example
+/// ```rust
+/// use durstr::{Error, FromParsedDuration, Parser};
+/// use std::time::Duration;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Millis(u32);
+///
+/// impl FromParsedDuration for Millis {
+///     fn from_parsed_duration(d: Duration) -> Result<Self, Error> {
+///         let millis = u32::try_from(d.as_millis())
+///             .map_err(|_| Error::OutOfRange(format!("{d:?}")))?;
+///         Ok(Millis(millis))
+///     }
+/// }
+///
+/// let parser = Parser::default();
+/// assert_eq!(parser.parse_into("1 min 5 ms"), Ok(Millis(60_005)));
+/// ```
+pub trait FromParsedDuration: Sized {
+    /// Converts a parsed `Duration` into `Self`.
+    fn from_parsed_duration(d: Duration) -> Result<Self, Error>;
+}
+
+impl FromParsedDuration for Duration {
+    fn from_parsed_duration(d: Duration) -> Result<Self, Error> {
+        Ok(d)
+    }
+}
+
+impl Parser {
+    /// Parses a string into any type implementing [`FromParsedDuration`].
+    ///
+    /// The output type is chosen at the call site:
+    /// ```
+    /// use durstr::Parser;
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    /// let dur = parser.parse_into::<Duration>("1 minute");
+    /// assert_eq!(dur, Ok(Duration::from_secs(60)));
+    /// ```
+    pub fn parse_into<T: FromParsedDuration>(&self, input: &str) -> Result<T, Error> {
+        T::from_parsed_duration(self.parse(input)?)
+    }
+}
@@ -0,0 +1,93 @@
+use std::{fmt, time::Duration};
+
+use crate::{Clause, Error, Parser};
+
+/// A lossless syntax tree of a duration string, which keeps every byte of the
+/// input, including whitespace, separators and casing.
+///
+/// Writing a `Cst` with `Display` gives back the input exactly, with any
+/// edits made through [`Cst::replace_component`] applied.
+///
+/// Returned by [`Parser::parse_cst`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cst<'a> {
+    source: &'a str,
+    components: Vec<Clause<'a>>,
+    edits: Vec<Option<String>>,
+}
+
+impl<'a> Cst<'a> {
+    /// The components of the input, in order. These always describe the
+    /// input, regardless of edits.
+    pub fn components(&self) -> &[Clause<'a>] {
+        &self.components
+    }
+
+    /// The total duration of the input.
+    pub fn duration(&self) -> Duration {
+        self.components.iter().map(Clause::duration).sum()
+    }
+
+    /// Replaces the text of the component at `index` when writing, leaving
+    /// the rest of the input untouched.
+    ///
+    /// The new text is written as is; it is not parsed or validated.
+    ///
+    /// ## Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use durstr::parse_cst;
+    ///
+    /// let mut cst = parse_cst("1 h,  30m  ").unwrap();
+    /// cst.replace_component(1, "45m");
+    /// assert_eq!(cst.to_string(), "1 h,  45m  ");
+    /// ```
+    pub fn replace_component(&mut self, index: usize, text: impl Into<String>) {
+        self.edits[index] = Some(text.into());
+    }
+}
+
+impl fmt::Display for Cst<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut end = 0;
+
+        for (component, edit) in self.components.iter().zip(&self.edits) {
+            f.write_str(&self.source[end..component.span().start])?;
+            f.write_str(edit.as_deref().unwrap_or(component.text()))?;
+            end = component.span().end;
+        }
+
+        f.write_str(&self.source[end..])
+    }
+}
+
+impl Parser {
+    /// Parses a string into a lossless [`Cst`], for tools that need to edit
+    /// durations without changing their formatting.
+    ///
+    /// The input must be a plain list of components: a leading "every",
+    /// quantifiers and business units are not supported.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::Parser;
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    /// let cst = parser.parse_cst("1 hour, 30m.").unwrap();
+    /// assert_eq!(cst.duration(), Duration::from_secs(5400));
+    /// assert_eq!(cst.components()[1].span(), 8..11);
+    /// assert_eq!(cst.to_string(), "1 hour, 30m.");
+    /// ```
+    pub fn parse_cst<'a>(&self, input: &'a str) -> Result<Cst<'a>, Error> {
+        let components = self.parse_clauses(input)?;
+
+        Ok(Cst {
+            source: input,
+            edits: vec![None; components.len()],
+            components,
+        })
+    }
+}
@@ -0,0 +1,66 @@
+/*!
+Compatibility with the `Debug` output of `Duration`, like "1.5s" or "2.3µs".
+
+This makes it possible to parse durations straight out of log lines written
+with `{:?}`.
+
+## Example
+
+```rust
+use durstr::debug;
+use std::time::Duration;
+
+let dur = Duration::from_nanos(2300);
+assert_eq!(format!("{dur:?}"), "2.3µs");
+assert_eq!(debug::parse(&format!("{dur:?}")), Ok(dur));
+
+let dur = debug::parse("1.5s");
+assert_eq!(dur, Ok(Duration::from_millis(1500)));
+```
+*/
+
+use std::{sync::LazyLock, time::Duration};
+
+use crate::{Error, Parser, ParserOptions, ParserUnits, Unit};
+
+static PARSER: LazyLock<Parser> = LazyLock::new(|| {
+    Parser::new(ParserOptions {
+        units: ParserUnits::debug(),
+        allow_fractions: true,
+        ..Default::default()
+    })
+});
+
+impl ParserUnits {
+    /// Returns the units used in the `Debug` output of `Duration`.
+    ///
+    /// Besides the micro sign (`µs`, U+00B5) used by `Debug`, the Greek mu
+    /// (`μs`, U+03BC) and `us` are accepted for microseconds.
+    ///
+    /// Units
+    /// - `ns`
+    /// - `µs`, `μs`, `us`
+    /// - `ms`
+    /// - `s`
+    pub fn debug() -> Self {
+        let mut units = ParserUnits::new();
+
+        units.add_unit("ns", Duration::from_nanos(1));
+        for u in ["µs", "μs", "us"] {
+            units.add_unit(u, Duration::from_micros(1));
+        }
+        units.add_standard_unit("ms", Unit::Millisecond);
+        units.add_standard_unit("s", Unit::Second);
+
+        units
+    }
+}
+
+/// Parses the `Debug` output of a `Duration` back into a `Duration`.
+///
+/// Fractional values are allowed and exact down to the nanosecond, so any
+/// `Debug` output without a precision (`{:?}`) round-trips. Like
+/// [`parse`](crate::parse), several components ("1s 500ms") are added up.
+pub fn parse(input: &str) -> Result<Duration, Error> {
+    PARSER.parse(input)
+}
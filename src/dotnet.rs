@@ -0,0 +1,72 @@
+/*!
+Compatibility with the .NET `TimeSpan` format, `[d.]hh:mm[:ss[.fffffff]]`.
+
+## Example
+
+```rust
+use durstr::dotnet;
+use std::time::Duration;
+
+let dur = dotnet::parse_timespan("1.02:03:04.5");
+assert_eq!(dur, Ok(Duration::from_millis(93_784_500)));
+
+assert_eq!(dotnet::format_timespan(Duration::from_millis(93_784_500)), "1.02:03:04.5000000");
+```
+*/
+
+use std::time::Duration;
+
+use crate::{
+    Error,
+    clock::{parse_clock, parse_digits},
+};
+
+/// Parses a string in the .NET `TimeSpan` format into a `Duration`.
+///
+/// This follows `TimeSpan.Parse`: either a whole number of days (`d`), or
+/// `[d.]hh:mm[:ss[.fffffff]]`, with hours up to 23, minutes and seconds up
+/// to 59, and up to 7 fractional digits. Surrounding whitespace is ignored.
+/// Negative time spans are not supported, as a `Duration` can't be negative.
+pub fn parse_timespan(input: &str) -> Result<Duration, Error> {
+    let input = input.trim();
+
+    let Some((days_hours, _)) = input.split_once(':') else {
+        return days_to_duration(input, Duration::ZERO);
+    };
+
+    let (days, clock) = match days_hours.split_once('.') {
+        Some((d, _)) => (d, &input[d.len() + 1..]),
+        None => ("0", input),
+    };
+
+    days_to_duration(days, parse_clock(clock, 23, 7)?)
+}
+
+fn days_to_duration(days: &str, time: Duration) -> Result<Duration, Error> {
+    parse_digits(days)?
+        .checked_mul(86400)
+        .and_then(|secs| Duration::from_secs(secs).checked_add(time))
+        .ok_or_else(|| Error::OutOfRange(days.to_owned()))
+}
+
+/// Formats a `Duration` in the .NET `TimeSpan` constant ("c") format,
+/// `[d.]hh:mm:ss[.fffffff]`.
+///
+/// The days are only included when non-zero, and the fraction is only
+/// included when there are sub-second ticks (units of 100 nanoseconds).
+pub fn format_timespan(d: Duration) -> String {
+    let secs = d.as_secs();
+    let (days, hours, minutes, seconds) =
+        (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    let ticks = d.subsec_nanos() / 100;
+
+    let mut s = String::new();
+    if days > 0 {
+        s += &format!("{days}.");
+    }
+    s += &format!("{hours:02}:{minutes:02}:{seconds:02}");
+    if ticks > 0 {
+        s += &format!(".{ticks:07}");
+    }
+    s
+}
@@ -0,0 +1,234 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    time::Duration,
+};
+
+use crate::{
+    Parser,
+    lexer::{ABBREVIATION_MARKS, DECIMAL_SEPARATORS, SEPARATORS},
+};
+
+/// A machine-readable description of what a [`Parser`] accepts, for tools
+/// that need to stay in sync with its configuration, such as web frontends
+/// or documentation generators.
+///
+/// Returned by [`Parser::export_spec`], and written as JSON by
+/// [`Spec::to_json`]. Business units and quantifiers are not described.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Spec {
+    /// The units, from the largest to the smallest.
+    pub units: Vec<UnitSpec>,
+    /// The characters skipped between tokens, besides ASCII whitespace.
+    pub separators: Vec<char>,
+    /// The characters read as a decimal point when a digit follows them.
+    /// Whether the fraction is then accepted depends on `allow_fractions`.
+    pub decimal_separators: Vec<char>,
+    /// The characters skipped right after a unit, as in "min.".
+    pub abbreviation_marks: Vec<char>,
+    /// The words that may start an input, with a bare unit after them
+    /// meaning one of that unit ("every hour").
+    pub interval_words: Vec<&'static str>,
+    pub ignore_case: bool,
+    pub allow_fractions: bool,
+    pub correct_typos: bool,
+    pub reject_zero: bool,
+    pub max_components: Option<usize>,
+    pub min_unit: Option<Duration>,
+    pub default_unit: Option<&'static str>,
+    pub tick_rate: Option<u32>,
+    pub sample_rate: Option<u32>,
+}
+
+/// A unit in a [`Spec`], with all of its aliases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnitSpec {
+    /// The names of the unit, in alphabetical order.
+    pub aliases: Vec<&'static str>,
+    pub duration: Duration,
+    /// Whether the unit's duration is only an approximation, like a month of
+    /// 30 days.
+    pub approximate: bool,
+}
+
+impl Spec {
+    /// Writes the spec as a JSON object, with the same field names.
+    /// Durations are written as objects of whole seconds and nanoseconds
+    /// (`{"secs": 60, "nanos": 0}`), and missing options as `null`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use durstr::{Parser, ParserOptions, ParserUnits};
+    /// use std::time::Duration;
+    ///
+    /// let mut units = ParserUnits::new();
+    /// units.add_unit("s", Duration::from_secs(1));
+    ///
+    /// let parser = Parser::new(ParserOptions { units, ..Default::default() });
+    /// let json = parser.export_spec().to_json();
+    /// assert!(json.starts_with(
+    ///     r#"{"units":[{"aliases":["s"],"duration":{"secs":1,"nanos":0},"approximate":false}],"#
+    /// ));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"units\":[");
+        for (i, unit) in self.units.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"aliases\":");
+            write_strings(&mut json, unit.aliases.iter().copied());
+            json.push_str(",\"duration\":");
+            write_duration(&mut json, unit.duration);
+            let _ = write!(json, ",\"approximate\":{}}}", unit.approximate);
+        }
+
+        json.push_str("],\"separators\":");
+        write_strings(&mut json, self.separators.iter().map(|c| c.to_string()));
+        json.push_str(",\"decimal_separators\":");
+        write_strings(
+            &mut json,
+            self.decimal_separators.iter().map(|c| c.to_string()),
+        );
+        json.push_str(",\"abbreviation_marks\":");
+        write_strings(
+            &mut json,
+            self.abbreviation_marks.iter().map(|c| c.to_string()),
+        );
+        json.push_str(",\"interval_words\":");
+        write_strings(&mut json, self.interval_words.iter().copied());
+
+        let _ = write!(
+            json,
+            ",\"ignore_case\":{},\"allow_fractions\":{},\"correct_typos\":{},\"reject_zero\":{}",
+            self.ignore_case, self.allow_fractions, self.correct_typos, self.reject_zero
+        );
+        json.push_str(",\"max_components\":");
+        write_option(&mut json, self.max_components, write_number);
+        json.push_str(",\"min_unit\":");
+        write_option(&mut json, self.min_unit, write_duration);
+        json.push_str(",\"default_unit\":");
+        write_option(&mut json, self.default_unit, write_string);
+        json.push_str(",\"tick_rate\":");
+        write_option(&mut json, self.tick_rate, write_number);
+        json.push_str(",\"sample_rate\":");
+        write_option(&mut json, self.sample_rate, write_number);
+
+        json.push('}');
+        json
+    }
+}
+
+fn write_duration(json: &mut String, d: Duration) {
+    let _ = write!(
+        json,
+        "{{\"secs\":{},\"nanos\":{}}}",
+        d.as_secs(),
+        d.subsec_nanos()
+    );
+}
+
+fn write_number(json: &mut String, n: impl fmt::Display) {
+    let _ = write!(json, "{n}");
+}
+
+fn write_option<T>(json: &mut String, value: Option<T>, write: impl FnOnce(&mut String, T)) {
+    match value {
+        Some(value) => write(json, value),
+        None => json.push_str("null"),
+    }
+}
+
+fn write_strings<S: AsRef<str>>(json: &mut String, strings: impl Iterator<Item = S>) {
+    json.push('[');
+    for (i, s) in strings.enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        write_string(json, s.as_ref());
+    }
+    json.push(']');
+}
+
+fn write_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            // invisible characters, like the directional marks among the
+            // separators, are escaped so they can be seen
+            c if c.is_control() || is_invisible(c) => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{206F}' | '\u{FEFF}'
+    )
+}
+
+impl Parser {
+    /// Returns a [`Spec`] describing the units and syntax this parser
+    /// accepts.
+    ///
+    /// Aliases of the same unit are grouped together: units with the same
+    /// duration and the same approximateness are one [`UnitSpec`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::Parser;
+    /// use std::time::Duration;
+    ///
+    /// let spec = Parser::default().export_spec();
+    /// assert_eq!(spec.units[0].aliases, ["h", "hour", "hours", "hr", "hrs"]);
+    /// assert_eq!(spec.units[0].duration, Duration::from_secs(3600));
+    /// ```
+    pub fn export_spec(&self) -> Spec {
+        let units = &self.options.units;
+        let mut grouped: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for (&name, &duration) in &units.values {
+            let approximate = units.approximate.contains(name);
+            grouped
+                .entry((duration, approximate))
+                .or_default()
+                .push(name);
+        }
+
+        let units = grouped
+            .into_iter()
+            .rev()
+            .map(|((duration, approximate), mut aliases)| {
+                aliases.sort();
+                UnitSpec {
+                    aliases,
+                    duration,
+                    approximate,
+                }
+            })
+            .collect();
+
+        Spec {
+            units,
+            separators: SEPARATORS.to_vec(),
+            decimal_separators: DECIMAL_SEPARATORS.to_vec(),
+            abbreviation_marks: ABBREVIATION_MARKS.to_vec(),
+            interval_words: vec!["every", "each"],
+            ignore_case: self.options.ignore_case,
+            allow_fractions: self.options.allow_fractions,
+            correct_typos: self.options.correct_typos,
+            reject_zero: self.options.reject_zero,
+            max_components: self.options.max_components,
+            min_unit: self.options.min_unit,
+            default_unit: self.options.default_unit,
+            tick_rate: self.options.tick_rate,
+            sample_rate: self.options.sample_rate,
+        }
+    }
+}
@@ -0,0 +1,461 @@
+#[cfg(feature = "calendar")]
+use std::time::SystemTime;
+use std::{fmt::Alignment, time::Duration};
+
+use crate::Unit;
+#[cfg(feature = "calendar")]
+use crate::calendar::{add_months, civil_from_days, unix_days};
+#[cfg(feature = "locale")]
+use crate::{Locale, PluralCategory};
+
+impl Unit {
+    /// Returns the name of this unit in the given style, in its plural form if
+    /// `plural` is set.
+    ///
+    /// Symbols don't have a plural form.
+    ///
+    /// ```rust
+    /// use durstr::{Unit, UnitStyle};
+    ///
+    /// assert_eq!(Unit::Minute.name(UnitStyle::Symbol, true), "m");
+    /// assert_eq!(Unit::Minute.name(UnitStyle::Short, true), "mins");
+    /// assert_eq!(Unit::Minute.name(UnitStyle::Long, false), "minute");
+    /// ```
+    pub fn name(self, style: UnitStyle, plural: bool) -> &'static str {
+        match (self, style, plural) {
+            (Unit::Millisecond, UnitStyle::Symbol, _) => "ms",
+            (Unit::Millisecond, UnitStyle::Short, false) => "msec",
+            (Unit::Millisecond, UnitStyle::Short, true) => "msecs",
+            (Unit::Millisecond, UnitStyle::Long, false) => "millisecond",
+            (Unit::Millisecond, UnitStyle::Long, true) => "milliseconds",
+            (Unit::Second, UnitStyle::Symbol, _) => "s",
+            (Unit::Second, UnitStyle::Short, false) => "sec",
+            (Unit::Second, UnitStyle::Short, true) => "secs",
+            (Unit::Second, UnitStyle::Long, false) => "second",
+            (Unit::Second, UnitStyle::Long, true) => "seconds",
+            (Unit::Minute, UnitStyle::Symbol, _) => "m",
+            (Unit::Minute, UnitStyle::Short, false) => "min",
+            (Unit::Minute, UnitStyle::Short, true) => "mins",
+            (Unit::Minute, UnitStyle::Long, false) => "minute",
+            (Unit::Minute, UnitStyle::Long, true) => "minutes",
+            (Unit::Hour, UnitStyle::Symbol, _) => "h",
+            (Unit::Hour, UnitStyle::Short, false) => "hr",
+            (Unit::Hour, UnitStyle::Short, true) => "hrs",
+            (Unit::Hour, UnitStyle::Long, false) => "hour",
+            (Unit::Hour, UnitStyle::Long, true) => "hours",
+        }
+    }
+
+    /// Returns the name of this unit that goes with `count`, for building
+    /// sentences around durations with the same words and plural rules as
+    /// [`Formatter`].
+    ///
+    /// As when formatting, symbols are the same in every language, and other
+    /// locales than English use full names for [`UnitStyle::Short`].
+    ///
+    /// ```rust
+    /// use durstr::{Locale, Unit, UnitStyle};
+    ///
+    /// assert_eq!(Unit::Minute.label(1, Locale::English, UnitStyle::Long), "minute");
+    /// assert_eq!(Unit::Minute.label(5, Locale::English, UnitStyle::Long), "minutes");
+    /// assert_eq!(Unit::Minute.label(5, Locale::Russian, UnitStyle::Long), "минут");
+    /// assert_eq!(Unit::Minute.label(5, Locale::Russian, UnitStyle::Symbol), "m");
+    /// ```
+    #[cfg(feature = "locale")]
+    pub fn label(self, count: u128, locale: Locale, style: UnitStyle) -> &'static str {
+        match (locale, style) {
+            (Locale::English, _) | (_, UnitStyle::Symbol) => self.name(style, count != 1),
+            _ => self.localized_name(locale, locale.plural_category(count)),
+        }
+    }
+
+    /// Returns the full name of this unit in `locale`, in the form that goes
+    /// with a number in the plural `category`.
+    ///
+    /// Use this over [`Unit::label`] for numbers that aren't whole, which
+    /// have their own category in some languages (`PluralCategory::Other`
+    /// in Russian, "1.5 минуты").
+    #[cfg(feature = "locale")]
+    pub fn localized_name(self, locale: Locale, category: PluralCategory) -> &'static str {
+        let [one, two, few, many, other] = match (locale, self) {
+            (Locale::English, _) => {
+                return self.name(UnitStyle::Long, category != PluralCategory::One);
+            }
+            (Locale::Arabic, Unit::Millisecond) => ["ملي ثانية"; 5],
+            (Locale::Arabic, Unit::Second) => ["ثانية", "ثانيتان", "ثوان", "ثانية", "ثانية"],
+            (Locale::Arabic, Unit::Minute) => ["دقيقة", "دقيقتان", "دقائق", "دقيقة", "دقيقة"],
+            (Locale::Arabic, Unit::Hour) => ["ساعة", "ساعتان", "ساعات", "ساعة", "ساعة"],
+            (Locale::Hebrew, Unit::Millisecond) => [
+                "מילישנייה",
+                "מילישניות",
+                "מילישניות",
+                "מילישניות",
+                "מילישניות",
+            ],
+            (Locale::Hebrew, Unit::Second) => ["שנייה", "שניות", "שניות", "שניות", "שניות"],
+            (Locale::Hebrew, Unit::Minute) => ["דקה", "דקות", "דקות", "דקות", "דקות"],
+            (Locale::Hebrew, Unit::Hour) => ["שעה", "שעות", "שעות", "שעות", "שעות"],
+            (Locale::Polish, Unit::Millisecond) => [
+                "milisekunda",
+                "milisekundy",
+                "milisekundy",
+                "milisekund",
+                "milisekundy",
+            ],
+            (Locale::Polish, Unit::Second) => {
+                ["sekunda", "sekundy", "sekundy", "sekund", "sekundy"]
+            }
+            (Locale::Polish, Unit::Minute) => ["minuta", "minuty", "minuty", "minut", "minuty"],
+            (Locale::Polish, Unit::Hour) => ["godzina", "godziny", "godziny", "godzin", "godziny"],
+            (Locale::Russian, Unit::Millisecond) => [
+                "миллисекунда",
+                "миллисекунды",
+                "миллисекунды",
+                "миллисекунд",
+                "миллисекунды",
+            ],
+            (Locale::Russian, Unit::Second) => {
+                ["секунда", "секунды", "секунды", "секунд", "секунды"]
+            }
+            (Locale::Russian, Unit::Minute) => ["минута", "минуты", "минуты", "минут", "минуты"],
+            (Locale::Russian, Unit::Hour) => ["час", "часа", "часа", "часов", "часа"],
+        };
+
+        match category {
+            PluralCategory::One => one,
+            PluralCategory::Two => two,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            PluralCategory::Zero | PluralCategory::Other => other,
+        }
+    }
+}
+
+/// Which family of unit aliases to use when formatting.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnitStyle {
+    /// Symbols, written right after the number: "1h 30m".
+    Symbol,
+    /// Abbreviations: "1 hr 30 mins".
+    Short,
+    /// Full words: "1 hour 30 minutes".
+    #[default]
+    Long,
+}
+
+/// Options to customize the behavior of a [`Formatter`].
+#[derive(Clone, Debug)]
+pub struct FormatterOptions {
+    pub style: UnitStyle,
+    /// Whether to use the plural form of units for quantities other than 1.
+    pub pluralize: bool,
+    /// The largest unit to use, e.g. [`Unit::Minute`] to express "2 hours 30
+    /// minutes" as "150 minutes". If `None`, all units are used.
+    pub largest_unit: Option<Unit>,
+    /// The smallest unit to use, e.g. [`Unit::Minute`] to leave out seconds
+    /// and milliseconds. Anything smaller is dropped, not rounded. If `None`,
+    /// milliseconds are used.
+    pub smallest_unit: Option<Unit>,
+    /// Writes zero components after the first one, and pads the numbers of
+    /// minutes and seconds to two digits and of milliseconds to three, so
+    /// "1h 2m" is "1h 02m 00s 000ms". The first component is not padded,
+    /// but always written if [`FormatterOptions::largest_unit`] is set, so
+    /// with hours and minutes only, 5 minutes is "0h 05m".
+    pub zero_pad: bool,
+    /// The minimum width of the output in characters. Shorter output is
+    /// padded with spaces according to [`FormatterOptions::align`].
+    pub width: Option<usize>,
+    /// Where to put shorter output within [`FormatterOptions::width`].
+    pub align: Alignment,
+    /// Formats durations as a single number of one unit with this many
+    /// decimal places (up to 9), rounded to the last place, like "1.50h" or
+    /// "90.25 seconds". The unit is [`FormatterOptions::largest_unit`] if
+    /// set, or else the largest unit the duration is at least one of, down to
+    /// [`FormatterOptions::smallest_unit`].
+    pub decimal_places: Option<usize>,
+    /// Formats durations as a count of audio samples at this many samples per
+    /// second instead, e.g. "4800 samples" for 100ms at 48 kHz, rounded to
+    /// the nearest sample. This is the inverse of
+    /// [`ParserOptions::sample_rate`](crate::ParserOptions::sample_rate).
+    pub sample_rate: Option<u32>,
+    /// The language of unit names, with plural forms chosen by the CLDR
+    /// plural rules of the locale. Symbols are the same in every language,
+    /// and only English has short names, so other locales use full names for
+    /// [`UnitStyle::Short`]. Output in other locales is meant for display, and
+    /// may not parse back.
+    #[cfg(feature = "locale")]
+    pub locale: Locale,
+}
+
+impl Default for FormatterOptions {
+    fn default() -> Self {
+        FormatterOptions {
+            style: UnitStyle::Long,
+            pluralize: true,
+            largest_unit: None,
+            smallest_unit: None,
+            zero_pad: false,
+            width: None,
+            align: Alignment::Right,
+            decimal_places: None,
+            sample_rate: None,
+            #[cfg(feature = "locale")]
+            locale: Locale::English,
+        }
+    }
+}
+
+/// A configurable formatter for durations.
+///
+/// The output of [`Formatter::format`] only uses the default units, so it
+/// can be parsed back with [`parse`](crate::parse). Precision below a
+/// millisecond is dropped. [`Formatter::format_between`] also uses calendar
+/// units, which `parse` doesn't accept.
+///
+/// ## Example
+/// ```rust
+/// use durstr::{Formatter, FormatterOptions, UnitStyle};
+/// use std::time::Duration;
+///
+/// let formatter = Formatter::new(FormatterOptions {
+///     style: UnitStyle::Symbol,
+///     ..Default::default()
+/// });
+/// assert_eq!(formatter.format(Duration::from_secs(5400)), "1h 30m");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Formatter {
+    options: FormatterOptions,
+}
+
+impl Formatter {
+    /// Create a new [`Formatter`] with provided [`FormatterOptions`]
+    pub fn new(options: FormatterOptions) -> Self {
+        Formatter { options }
+    }
+
+    /// Formats a `Duration` as a human-readable string, using the largest
+    /// units first and leaving out zero components.
+    pub fn format(&self, d: Duration) -> String {
+        let s = match (self.options.sample_rate, self.options.decimal_places) {
+            (Some(rate), _) => self.format_samples(d, rate),
+            (None, Some(places)) => self.format_decimal(d, places),
+            (None, None) => self.format_components(d),
+        };
+        self.pad(s)
+    }
+
+    fn format_components(&self, d: Duration) -> String {
+        let mut rest = d.as_millis();
+        let mut parts = vec![];
+
+        let (largest, smallest) = (self.options.largest_unit, self.options.smallest_unit);
+        let units = Unit::ALL
+            .into_iter()
+            .filter(|&u| largest.is_none_or(|largest| u <= largest))
+            .filter(|&u| smallest.is_none_or(|smallest| u >= smallest));
+
+        for unit in units {
+            let unit_millis = unit.duration().as_millis();
+            let n = rest / unit_millis;
+            rest %= unit_millis;
+
+            let first = parts.is_empty();
+            let pad = self.options.zero_pad && (!first || largest.is_some());
+            if n > 0 || pad {
+                parts.push(self.format_component(n, unit, pad && !first));
+            }
+        }
+
+        if parts.is_empty() {
+            let unit = match largest {
+                Some(largest) => largest.min(Unit::Second),
+                None => Unit::Second,
+            };
+            let unit = smallest.map_or(unit, |smallest| unit.max(smallest));
+            parts.push(self.format_component(0, unit, false));
+        }
+
+        parts.join(" ")
+    }
+
+    fn format_decimal(&self, d: Duration, places: usize) -> String {
+        let (nanos, places) = (d.as_nanos(), places.min(9));
+        let smallest = self.options.smallest_unit.unwrap_or(Unit::Millisecond);
+        let unit = match self.options.largest_unit {
+            Some(largest) => largest,
+            None if nanos == 0 => Unit::Second.max(smallest),
+            None => Unit::ALL
+                .into_iter()
+                .find(|u| u >= &smallest && nanos >= u.duration().as_nanos())
+                .unwrap_or(smallest),
+        };
+
+        let unit_nanos = unit.duration().as_nanos();
+        let scale = 10u128.pow(places as u32);
+        let scaled = (nanos * scale + unit_nanos / 2) / unit_nanos;
+        let (whole, frac) = (scaled / scale, scaled % scale);
+
+        let (n, name) = match places {
+            0 => (whole.to_string(), self.unit_name(Some(whole), unit)),
+            _ => (
+                format!("{whole}.{frac:0places$}"),
+                self.unit_name(None, unit),
+            ),
+        };
+        match self.options.style {
+            UnitStyle::Symbol => format!("{n}{name}"),
+            UnitStyle::Short | UnitStyle::Long => format!("{n} {name}"),
+        }
+    }
+
+    /// Pads `s` with spaces to [`FormatterOptions::width`].
+    fn pad(&self, s: String) -> String {
+        let width = self.options.width.unwrap_or(0);
+        match self.options.align {
+            Alignment::Left => format!("{s:<width$}"),
+            Alignment::Right => format!("{s:>width$}"),
+            Alignment::Center => format!("{s:^width$}"),
+        }
+    }
+
+    /// Formats the time between two instants in calendar terms, as years,
+    /// months and days, followed by the rest of the time as in
+    /// [`Formatter::format`].
+    ///
+    /// Months are counted on the UTC calendar from the earlier instant, so
+    /// January 31 to March 3 is "1 month 3 days", with the day clamped to
+    /// the end of shorter months as in
+    /// [`Parser::parse_from`](crate::Parser::parse_from). The order of the
+    /// instants doesn't matter. Calendar units are always in English.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use durstr::Formatter;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// // 2024-01-31T00:00:00Z and 2024-03-03T12:00:00Z
+    /// let start = UNIX_EPOCH + Duration::from_secs(19753 * 86400);
+    /// let end = UNIX_EPOCH + Duration::from_secs(19785 * 86400 + 43200);
+    ///
+    /// let formatter = Formatter::default();
+    /// assert_eq!(formatter.format_between(start, end), "1 month 3 days 12 hours");
+    /// ```
+    #[cfg(feature = "calendar")]
+    pub fn format_between(&self, start: SystemTime, end: SystemTime) -> String {
+        const DAY: u64 = 86400;
+
+        let (start, end) = match start <= end {
+            true => (start, end),
+            false => (end, start),
+        };
+        let (start_days, end_days) = (unix_days(start), unix_days(end));
+        let month_index = |days| {
+            let (y, m, _) = civil_from_days(days);
+            y * 12 + i64::from(m)
+        };
+        let after_months = |months: u64| {
+            let days = add_months(start_days, i64::try_from(months).ok()?)? - start_days;
+            start.checked_add(Duration::from_secs(days as u64 * DAY))
+        };
+
+        let mut months = (month_index(end_days) - month_index(start_days)) as u64;
+        if after_months(months).is_none_or(|t| t > end) {
+            months -= 1;
+        }
+
+        let rest = after_months(months)
+            .and_then(|t| end.duration_since(t).ok())
+            .unwrap_or_default();
+        let days = rest.as_secs() / DAY;
+        let rest = rest - Duration::from_secs(days * DAY);
+
+        let mut parts = vec![];
+        for (n, names) in [
+            (months / 12, ["y", "yr", "yrs", "year", "years"]),
+            (months % 12, ["mo", "mo", "mos", "month", "months"]),
+            (days, ["d", "day", "days", "day", "days"]),
+        ] {
+            if n > 0 {
+                parts.push(self.format_calendar_component(n, names));
+            }
+        }
+        if !rest.is_zero() || parts.is_empty() {
+            parts.push(self.format_components(rest));
+        }
+
+        self.pad(parts.join(" "))
+    }
+
+    /// Formats `n` of a calendar unit, given its symbol and short and long
+    /// names in the singular and plural.
+    #[cfg(feature = "calendar")]
+    fn format_calendar_component(&self, n: u64, names: [&str; 5]) -> String {
+        let [symbol, short, shorts, long, longs] = names;
+        let plural = self.options.pluralize && n != 1;
+
+        match (self.options.style, plural) {
+            (UnitStyle::Symbol, _) => format!("{n}{symbol}"),
+            (UnitStyle::Short, false) => format!("{n} {short}"),
+            (UnitStyle::Short, true) => format!("{n} {shorts}"),
+            (UnitStyle::Long, false) => format!("{n} {long}"),
+            (UnitStyle::Long, true) => format!("{n} {longs}"),
+        }
+    }
+
+    fn format_samples(&self, d: Duration, rate: u32) -> String {
+        let n = (d.as_nanos() * u128::from(rate) + 500_000_000) / 1_000_000_000;
+
+        match self.options.pluralize && n != 1 {
+            true => format!("{n} samples"),
+            false => format!("{n} sample"),
+        }
+    }
+
+    fn format_component(&self, n: u128, unit: Unit, pad: bool) -> String {
+        let name = self.unit_name(Some(n), unit);
+        let digits = match (pad, unit) {
+            (false, _) | (true, Unit::Hour) => 1,
+            (true, Unit::Minute | Unit::Second) => 2,
+            (true, Unit::Millisecond) => 3,
+        };
+
+        match self.options.style {
+            UnitStyle::Symbol => format!("{n:0digits$}{name}"),
+            UnitStyle::Short | UnitStyle::Long => format!("{n:0digits$} {name}"),
+        }
+    }
+
+    /// Returns the name of `unit` that goes with `n`, where `None` is a
+    /// number with a fractional part.
+    fn unit_name(&self, n: Option<u128>, unit: Unit) -> &'static str {
+        #[cfg(feature = "locale")]
+        if self.options.locale != Locale::English && self.options.style != UnitStyle::Symbol {
+            let category = match (self.options.pluralize, n) {
+                (false, _) => PluralCategory::One,
+                (true, Some(n)) => self.options.locale.plural_category(n),
+                (true, None) => PluralCategory::Other,
+            };
+            return unit.localized_name(self.options.locale, category);
+        }
+
+        unit.name(self.options.style, self.options.pluralize && n != Some(1))
+    }
+}
+
+/// Formats a `Duration` as a human-readable string.
+///
+/// This is a convenience wrapper around a default [`Formatter`], which uses
+/// full, pluralized unit names.
+///
+/// ## Examples
+/// ```
+/// use durstr::format;
+/// use std::time::Duration;
+///
+/// assert_eq!(format(Duration::from_secs(741)), "12 minutes 21 seconds");
+/// assert_eq!(format(Duration::from_millis(1001)), "1 second 1 millisecond");
+/// ```
+pub fn format(d: Duration) -> String {
+    Formatter::default().format(d)
+}
@@ -0,0 +1,104 @@
+/*!
+Helpers for durations in HTTP headers.
+
+HTTP headers express durations as delta-seconds, a whole number of seconds.
+
+## Example
+
+```rust
+use durstr::http;
+use std::time::Duration;
+
+let header = "public, max-age=3600, stale-while-revalidate=60";
+assert_eq!(http::max_age(header), Ok(Some(Duration::from_secs(3600))));
+assert_eq!(http::stale_while_revalidate(header), Ok(Some(Duration::from_secs(60))));
+
+assert_eq!(http::parse_retry_after("120"), Ok(Duration::from_secs(120)));
+assert_eq!(http::format_retry_after(Duration::from_secs(120)), "120");
+```
+*/
+
+use std::time::Duration;
+
+use crate::{Error, clock::parse_digits};
+
+/// Returns the value of a delta-seconds directive in a `Cache-Control`
+/// header, or `None` if the directive is not present.
+///
+/// Directive names are matched case-insensitively, and quoted values
+/// (`max-age="60"`) are accepted.
+pub fn cache_control_directive(header: &str, name: &str) -> Result<Option<Duration>, Error> {
+    for directive in header.split(',') {
+        let (k, v) = match directive.split_once('=') {
+            Some((k, v)) => (k.trim(), Some(v.trim())),
+            None => (directive.trim(), None),
+        };
+        if !k.eq_ignore_ascii_case(name) {
+            continue;
+        }
+
+        let v = v.ok_or(Error::ExpectedNumber)?;
+        let v = v
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(v);
+        return parse_delta_seconds(v).map(Some);
+    }
+
+    Ok(None)
+}
+
+/// Returns the `max-age` of a `Cache-Control` header, if present.
+pub fn max_age(header: &str) -> Result<Option<Duration>, Error> {
+    cache_control_directive(header, "max-age")
+}
+
+/// Returns the `s-maxage` of a `Cache-Control` header, if present.
+pub fn s_maxage(header: &str) -> Result<Option<Duration>, Error> {
+    cache_control_directive(header, "s-maxage")
+}
+
+/// Returns the `stale-while-revalidate` of a `Cache-Control` header, if present.
+pub fn stale_while_revalidate(header: &str) -> Result<Option<Duration>, Error> {
+    cache_control_directive(header, "stale-while-revalidate")
+}
+
+/// Returns the `stale-if-error` of a `Cache-Control` header, if present.
+pub fn stale_if_error(header: &str) -> Result<Option<Duration>, Error> {
+    cache_control_directive(header, "stale-if-error")
+}
+
+/// Parses a `Retry-After` header in its delta-seconds form.
+///
+/// The HTTP-date form is not supported, and results in an
+/// [`Error::UnexpectedChar`].
+pub fn parse_retry_after(value: &str) -> Result<Duration, Error> {
+    parse_delta_seconds(value.trim())
+}
+
+/// Formats a `Cache-Control` directive with a delta-seconds value, such as
+/// `max-age=60`.
+///
+/// Sub-second precision is truncated, so a response is never cached for
+/// longer than `d`.
+pub fn format_cache_control_directive(name: &str, d: Duration) -> String {
+    format!("{name}={}", d.as_secs())
+}
+
+/// Formats a `max-age` directive for a `Cache-Control` header.
+pub fn format_max_age(d: Duration) -> String {
+    format_cache_control_directive("max-age", d)
+}
+
+/// Formats a `Retry-After` header value in its delta-seconds form.
+///
+/// Sub-second precision is rounded up, so a client never retries before `d`
+/// has passed, except at the largest number of seconds.
+pub fn format_retry_after(d: Duration) -> String {
+    let secs = d.as_secs().saturating_add(u64::from(d.subsec_nanos() > 0));
+    secs.to_string()
+}
+
+fn parse_delta_seconds(s: &str) -> Result<Duration, Error> {
+    parse_digits(s).map(Duration::from_secs)
+}
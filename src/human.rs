@@ -0,0 +1,199 @@
+use std::{
+    fmt,
+    ops::{Add, Mul, Sub},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{Clause, Error, Parser, format};
+
+/// A parsed `Duration` that remembers the units it was written with, so it
+/// can be displayed in the same style after arithmetic.
+///
+/// `Display` writes the duration with the units of the input, largest first
+/// ("90m" + "30m" is "120m", not "2h"). Adding two durations keeps the units
+/// of both, preferring the spelling of the left one. If the duration can't
+/// be written exactly with those units, it is written with [`format()`]
+/// instead.
+///
+/// Arithmetic panics on overflow and underflow, like it does for `Duration`.
+///
+/// ## Example
+/// ```rust
+/// use durstr::HumanDuration;
+///
+/// let a: HumanDuration = "90m".parse().unwrap();
+/// let b: HumanDuration = "30m".parse().unwrap();
+/// assert_eq!((a + b).to_string(), "120m");
+///
+/// let d: HumanDuration = "1 hr 15 mins".parse().unwrap();
+/// assert_eq!((d * 2).to_string(), "2 hrs 30 mins");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HumanDuration {
+    duration: Duration,
+    /// The units of the input, from the largest to the smallest.
+    units: Vec<Spelling>,
+}
+
+/// How a unit was written in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Spelling {
+    duration: Duration,
+    /// The form used for a count of one.
+    one: String,
+    /// The form used for other counts.
+    other: String,
+    /// Whether the number and the unit were separated by whitespace.
+    spaced: bool,
+}
+
+impl HumanDuration {
+    /// The parsed duration.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl From<HumanDuration> for Duration {
+    fn from(d: HumanDuration) -> Self {
+        d.duration
+    }
+}
+
+impl FromStr for HumanDuration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::default().parse_human(s)
+    }
+}
+
+impl Add for HumanDuration {
+    type Output = HumanDuration;
+
+    fn add(mut self, rhs: HumanDuration) -> HumanDuration {
+        self.duration += rhs.duration;
+        for spelling in rhs.units {
+            insert_spelling(&mut self.units, spelling);
+        }
+        self
+    }
+}
+
+impl Sub for HumanDuration {
+    type Output = HumanDuration;
+
+    fn sub(mut self, rhs: HumanDuration) -> HumanDuration {
+        self.duration -= rhs.duration;
+        for spelling in rhs.units {
+            insert_spelling(&mut self.units, spelling);
+        }
+        self
+    }
+}
+
+impl Mul<u32> for HumanDuration {
+    type Output = HumanDuration;
+
+    fn mul(mut self, rhs: u32) -> HumanDuration {
+        self.duration *= rhs;
+        self
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rest = self.duration.as_nanos();
+        let mut parts = vec![];
+
+        for spelling in self.units.iter().filter(|s| !s.duration.is_zero()) {
+            let unit = spelling.duration.as_nanos();
+            let n = rest / unit;
+            rest %= unit;
+
+            if n > 0 {
+                parts.push((n, spelling));
+            }
+        }
+
+        let smallest = self.units.last();
+        if rest != 0 || smallest.is_none() {
+            return f.write_str(&format(self.duration));
+        }
+        if parts.is_empty() {
+            parts.extend(smallest.map(|s| (0, s)));
+        }
+
+        for (i, (n, spelling)) in parts.into_iter().enumerate() {
+            let name = match n {
+                1 => &spelling.one,
+                _ => &spelling.other,
+            };
+            let sep = if spelling.spaced { " " } else { "" };
+
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{n}{sep}{name}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds `spelling` to `units`, unless a unit of the same length is already
+/// there.
+fn insert_spelling(units: &mut Vec<Spelling>, spelling: Spelling) {
+    if let Err(i) = units.binary_search_by(|s| spelling.duration.cmp(&s.duration)) {
+        units.insert(i, spelling);
+    }
+}
+
+impl Parser {
+    /// Parses a string into a [`HumanDuration`], which remembers the units
+    /// of the input for display.
+    ///
+    /// As with [`Parser::parse_cst`], the input must be a plain list of
+    /// components: a leading "every", quantifiers and business units are not
+    /// supported.
+    ///
+    /// Singular and plural forms of a unit are switched when displaying a
+    /// different count, as long as both are units of the parser ("1 hour"
+    /// times 2 is "2 hours").
+    pub fn parse_human(&self, input: &str) -> Result<HumanDuration, Error> {
+        let clauses = self.parse_clauses(input)?;
+        let duration = clauses.iter().map(Clause::duration).sum();
+
+        let mut units = vec![];
+        for clause in &clauses {
+            let unit = clause.unit_text();
+            let unit_duration = self.get_unit_duration(unit)?;
+            let is_alias = |u: &str| {
+                let u = self.normalize_unit(u);
+                self.options.units.get_duration(&u) == Some(&unit_duration)
+            };
+
+            let one = match unit.strip_suffix('s') {
+                Some(singular) if is_alias(singular) => singular.to_owned(),
+                _ => unit.to_owned(),
+            };
+            let other = match format!("{one}s") {
+                plural if is_alias(&plural) => plural,
+                _ => unit.to_owned(),
+            };
+
+            let spelling = Spelling {
+                duration: unit_duration,
+                one,
+                other,
+                // a number always ends with a digit
+                spaced: !clause.text()[..clause.text().len() - unit.len()]
+                    .ends_with(char::is_numeric),
+            };
+            insert_spelling(&mut units, spelling);
+        }
+
+        Ok(HumanDuration { duration, units })
+    }
+}
@@ -0,0 +1,158 @@
+use std::{ops::Range, time::Duration};
+
+use crate::{
+    Error, Parser, Warning,
+    lexer::{Lexer, Quantity, TokenKind},
+};
+
+/// How many bytes past the end of a token the lexer may look at to decide
+/// where the token ends: a decimal separator and a digit, each of up to two
+/// bytes in UTF-8 ("٫٥").
+const LOOKAHEAD: usize = 4;
+
+/// A parser for a string that is edited in place, such as a text field that
+/// is validated as the user types.
+///
+/// The tokens of the text are kept between edits, so an edit only rescans the
+/// text from just before it, while the unchanged prefix is reused. The
+/// result, and any warnings, are updated after every edit.
+///
+/// ## Example
+/// ```rust
+/// use durstr::{Error, IncrementalParser, Parser};
+/// use std::time::Duration;
+///
+/// let mut field = IncrementalParser::new(Parser::default());
+/// field.insert(0, "1h 3");
+/// assert_eq!(field.result(), Err(Error::ExpectedUnit));
+///
+/// field.insert(4, "0m");
+/// assert_eq!(field.result(), Ok(Duration::from_secs(5400)));
+///
+/// field.delete(0..3);
+/// assert_eq!(field.text(), "30m");
+/// assert_eq!(field.result(), Ok(Duration::from_secs(1800)));
+/// ```
+pub struct IncrementalParser {
+    parser: Parser,
+    text: String,
+    tokens: Vec<CachedToken>,
+    result: Result<(Duration, Vec<Warning>), Error>,
+}
+
+/// A token of the text, which can't borrow from it as it is edited.
+struct CachedToken {
+    number: Option<Quantity>,
+    span: Range<usize>,
+    /// Where the lexer continued after the token, which is past the span
+    /// when it skipped a period after a unit.
+    resume: usize,
+}
+
+impl IncrementalParser {
+    /// Creates an incremental parser with empty text, which parses using
+    /// `parser`.
+    pub fn new(parser: Parser) -> Self {
+        let mut incremental = IncrementalParser {
+            parser,
+            text: String::new(),
+            tokens: vec![],
+            result: Ok((Duration::ZERO, vec![])),
+        };
+        incremental.update(0);
+        incremental
+    }
+
+    /// The current text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The result of parsing the current text, as with [`Parser::parse`].
+    pub fn result(&self) -> Result<Duration, Error> {
+        self.result.clone().map(|(d, _)| d)
+    }
+
+    /// The warnings about the current text, as with
+    /// [`Parser::parse_with_warnings`]. These are empty if parsing failed.
+    pub fn warnings(&self) -> &[Warning] {
+        match &self.result {
+            Ok((_, warnings)) => warnings,
+            Err(_) => &[],
+        }
+    }
+
+    /// Inserts `text` at the byte offset `at`.
+    ///
+    /// ## Panics
+    /// Panics if `at` is out of bounds or not on a `char` boundary.
+    pub fn insert(&mut self, at: usize, text: &str) {
+        self.replace(at..at, text);
+    }
+
+    /// Deletes the text in the byte range `range`.
+    ///
+    /// ## Panics
+    /// Panics if `range` is out of bounds or not on `char` boundaries.
+    pub fn delete(&mut self, range: Range<usize>) {
+        self.replace(range, "");
+    }
+
+    /// Replaces the text in the byte range `range` with `text`.
+    ///
+    /// ## Panics
+    /// Panics if `range` is out of bounds or not on `char` boundaries.
+    pub fn replace(&mut self, range: Range<usize>, text: &str) {
+        let start = range.start;
+        self.text.replace_range(range, text);
+        self.update(start);
+    }
+
+    /// Rescans the text after the last token that can't be affected by an
+    /// edit at `start`, then parses the tokens again.
+    fn update(&mut self, start: usize) {
+        let keep = self
+            .tokens
+            .iter()
+            .take_while(|t| t.resume + LOOKAHEAD <= start)
+            .count();
+        self.tokens.truncate(keep);
+
+        let offset = self.tokens.last().map_or(0, |t| t.resume);
+        let mut lexer = Lexer::new(&self.text[offset..]);
+        let mut lex_error = None;
+        loop {
+            match lexer.next_token() {
+                Ok(Some(token)) => self.tokens.push(CachedToken {
+                    number: match token.kind {
+                        TokenKind::Number(n) => Some(n),
+                        TokenKind::Unit(_) => None,
+                    },
+                    span: token.span.start + offset..token.span.end + offset,
+                    resume: lexer.offset() + offset,
+                }),
+                Ok(None) => break,
+                Err(e) => {
+                    lex_error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        self.result = match lex_error {
+            Some(e) => Err(e.into()),
+            None => {
+                let kinds = self
+                    .tokens
+                    .iter()
+                    .map(|t| match t.number {
+                        Some(n) => TokenKind::Number(n),
+                        None => TokenKind::Unit(&self.text[t.span.clone()]),
+                    })
+                    .collect();
+                let (tokens, _) = self.parser.prepare_tokens(&self.text, kinds);
+                self.parser.parse_tokens_with_warnings(tokens)
+            }
+        };
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use crate::{Error, Parser, add_durations};
+
+/// A parsed duration, along with metadata about how it was parsed.
+///
+/// Returned by [`Parser::parse_with_info`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseInfo {
+    /// The parsed duration.
+    pub duration: Duration,
+    /// Whether any approximate units or fuzzy quantifiers ("a few") were
+    /// used, meaning the duration is an estimate. See
+    /// [`ParserUnits::add_approximate_unit`](crate::ParserUnits::add_approximate_unit).
+    pub approximate: bool,
+    /// The units that appeared in the input, in order.
+    ///
+    /// When `ignore_case` is enabled, these are case folded. Units corrected
+    /// from typos are listed as the unit they were corrected to.
+    pub units: Vec<String>,
+    /// Whether the input was phrased as an interval, with a leading "every"
+    /// or "each".
+    pub interval: bool,
+    /// Whether the parser recovered from a mistake in the input instead of
+    /// failing, such as a unit that was taken to be a typo of a known unit.
+    /// See [`ParserOptions::correct_typos`](crate::ParserOptions::correct_typos).
+    pub recovered: bool,
+}
+
+impl Parser {
+    /// Parses a string into a `Duration`, like [`Parser::parse`], and returns
+    /// it along with metadata about the parse.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::{Parser, ParserOptions, ParserUnits};
+    /// use std::time::Duration;
+    ///
+    /// let mut units = ParserUnits::default();
+    /// units.add_approximate_unit("month", Duration::from_secs(86400) * 30);
+    /// let parser = Parser::new(ParserOptions { units, ..Default::default() });
+    ///
+    /// let info = parser.parse_with_info("1 month 2 h").unwrap();
+    /// assert!(info.approximate);
+    /// assert_eq!(info.units, ["month", "h"]);
+    /// ```
+    pub fn parse_with_info(&self, input: &str) -> Result<ParseInfo, Error> {
+        let (tokens, prepared) = self.scan_prepared(input)?;
+        let mut info = ParseInfo {
+            duration: Duration::ZERO,
+            approximate: prepared.quantified,
+            units: vec![],
+            interval: prepared.interval,
+            recovered: false,
+        };
+
+        self.for_each_clause(tokens, |num, unit| {
+            info.duration = add_durations(info.duration, self.clause_duration(num, unit)?)?;
+
+            let mut unit = self.normalize_unit(unit);
+            if let (_, Some(corrected)) = self.lookup_unit(&unit)? {
+                info.recovered = true;
+                unit = corrected.into();
+            }
+            info.approximate |= self.options.units.is_approximate(&unit);
+            info.units.push(unit.into_owned());
+            Ok(())
+        })?;
+
+        Ok(info)
+    }
+}
@@ -0,0 +1,370 @@
+/*!
+ISO 8601 durations and time intervals, like "PT1H30M" and
+"2024-01-01T00:00Z/P1D".
+
+## Example
+
+```rust
+use durstr::iso::{self, Repeat};
+use std::time::Duration;
+
+let dur = iso::parse_duration("PT1H30M");
+assert_eq!(dur, Ok(Duration::from_secs(5400)));
+
+let interval = iso::parse_interval("2024-01-31T00:00Z/P1M").unwrap();
+assert_eq!(interval.duration(), Duration::from_secs(29 * 86400));
+
+let interval = iso::parse_interval("R5/PT1H").unwrap();
+assert_eq!(interval.duration(), Duration::from_secs(3600));
+assert_eq!(interval.repeat(), Some(Repeat::Times(5)));
+```
+*/
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    Error,
+    calendar::{add_months, days_from_civil, days_in_month, unix_days},
+    clock,
+    lexer::Quantity,
+    whole_quantity,
+};
+
+const DAY: Duration = Duration::from_secs(86400);
+
+/// How often an [`Interval`] repeats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Repeat {
+    /// A number of repetitions, like "R5".
+    Times(u32),
+    /// Repetitions without end, written "R".
+    Forever,
+}
+
+/// An ISO 8601 time interval, returned by [`parse_interval`].
+///
+/// An interval has a duration, and may be anchored to a start, an end or
+/// both, and repeat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interval {
+    start: Option<SystemTime>,
+    end: Option<SystemTime>,
+    duration: Duration,
+    repeat: Option<Repeat>,
+}
+
+impl Interval {
+    /// The start of the interval, if it was given or follows from the end
+    /// and the duration.
+    pub fn start(&self) -> Option<SystemTime> {
+        self.start
+    }
+
+    /// The end of the interval, if it was given or follows from the start
+    /// and the duration.
+    pub fn end(&self) -> Option<SystemTime> {
+        self.end
+    }
+
+    /// The length of the interval. Years and months are resolved on the
+    /// calendar from the start or end.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// How often the interval repeats, or `None` if it doesn't.
+    pub fn repeat(&self) -> Option<Repeat> {
+        self.repeat
+    }
+}
+
+/// A duration as written, with the months kept apart, as their length
+/// depends on the date.
+struct Nominal {
+    months: u32,
+    fixed: Duration,
+}
+
+impl Nominal {
+    fn after(&self, start: SystemTime) -> Option<SystemTime> {
+        let days = unix_days(start);
+        let shift = add_months(days, i64::from(self.months))? - days;
+        start
+            .checked_add(DAY * u32::try_from(shift).ok()?)?
+            .checked_add(self.fixed)
+    }
+
+    fn before(&self, end: SystemTime) -> Option<SystemTime> {
+        let end = end.checked_sub(self.fixed)?;
+        let days = unix_days(end);
+        let shift = days - add_months(days, -i64::from(self.months))?;
+        end.checked_sub(DAY * u32::try_from(shift).ok()?)
+    }
+}
+
+/// Parses an ISO 8601 duration, "PnW" or "PnYnMnDTnHnMnS", into a
+/// `Duration`.
+///
+/// Components may be left out, but must be in this order, and only the last
+/// one may have a fraction, with a "." or ",". A day is 24 hours and a week
+/// is 7 days. Years and months have no fixed length, so they are an
+/// [`Error::UnexpectedUnit`] here; use [`parse_interval`] with a start or end
+/// to resolve them.
+///
+/// ## Examples
+/// ```
+/// use durstr::iso;
+/// use std::time::Duration;
+///
+/// assert_eq!(iso::parse_duration("P1DT12H"), Ok(Duration::from_secs(129600)));
+/// assert_eq!(iso::parse_duration("PT0,5S"), Ok(Duration::from_millis(500)));
+/// ```
+pub fn parse_duration(input: &str) -> Result<Duration, Error> {
+    parse_nominal(input, false).map(|n| n.fixed)
+}
+
+/// Parses an ISO 8601 time interval into an [`Interval`].
+///
+/// An interval is a start and an end ("2024-01-01T00:00Z/2024-01-02T00:00Z"),
+/// a start and a duration ("2024-01-01T00:00Z/P1D"), a duration and an end
+/// ("P1D/2024-01-02T00:00Z") or just a duration ("PT1H"), optionally
+/// preceded by a number of repetitions ("R5/PT1H", or "R/PT1H" to repeat
+/// without end).
+///
+/// Dates and times are in the extended format, "YYYY-MM-DD" optionally
+/// followed by "Thh:mm[:ss[.f]]" and a UTC offset ("Z", "+02:00"). Times
+/// without an offset are taken to be in UTC. Years and months in durations
+/// are only accepted with a start or an end to apply them to.
+///
+/// A combination of parts that doesn't make an interval, such as two
+/// durations, or an end before the start, is an [`Error::InvalidInterval`].
+///
+/// ## Examples
+/// ```
+/// use durstr::iso;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let interval = iso::parse_interval("P1D/2024-01-02T00:00Z").unwrap();
+/// assert_eq!(interval.start(), Some(UNIX_EPOCH + Duration::from_secs(19723 * 86400)));
+/// assert_eq!(interval.duration(), Duration::from_secs(86400));
+/// ```
+pub fn parse_interval(input: &str) -> Result<Interval, Error> {
+    let invalid = || Error::InvalidInterval(input.to_owned());
+
+    let (repeat, rest) = match input.strip_prefix('R') {
+        Some(rest) => {
+            let (count, rest) = rest.split_once('/').ok_or_else(invalid)?;
+            let repeat = match count {
+                "" => Repeat::Forever,
+                count => {
+                    let count = clock::parse_digits(count)?;
+                    Repeat::Times(u32::try_from(count).map_err(|_| Error::Overflow)?)
+                }
+            };
+            (Some(repeat), rest)
+        }
+        None => (None, input),
+    };
+
+    let is_duration = |s: &str| s.starts_with('P');
+    let parts: Vec<_> = rest.split('/').collect();
+    let (start, end) = match parts[..] {
+        [duration] if is_duration(duration) => {
+            return Ok(Interval {
+                start: None,
+                end: None,
+                duration: parse_duration(duration)?,
+                repeat,
+            });
+        }
+        [start, end] if !is_duration(start) && !is_duration(end) => {
+            (parse_date_time(start)?, parse_date_time(end)?)
+        }
+        [start, duration] if !is_duration(start) && is_duration(duration) => {
+            let start = parse_date_time(start)?;
+            let end = parse_nominal(duration, true)?.after(start);
+            (start, end.ok_or(Error::Overflow)?)
+        }
+        [duration, end] if is_duration(duration) && !is_duration(end) => {
+            let end = parse_date_time(end)?;
+            let start = parse_nominal(duration, true)?.before(end);
+            (start.ok_or(Error::Overflow)?, end)
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok(Interval {
+        start: Some(start),
+        end: Some(end),
+        duration: end.duration_since(start).map_err(|_| invalid())?,
+        repeat,
+    })
+}
+
+/// Parses a duration, accepting years and months only if `calendar` is set.
+fn parse_nominal(input: &str, calendar: bool) -> Result<Nominal, Error> {
+    let rest = match input.strip_prefix('P') {
+        Some(rest) => rest,
+        None => {
+            return Err(input
+                .chars()
+                .next()
+                .map_or(Error::ExpectedUnit, Error::UnexpectedChar));
+        }
+    };
+    let (date, time) = match rest.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+    if time == Some("") || (date.is_empty() && time.is_none()) {
+        return Err(Error::ExpectedNumber);
+    }
+
+    let mut nominal = Nominal {
+        months: 0,
+        fixed: Duration::ZERO,
+    };
+    let mut fraction = false;
+
+    for (mut part, designators, is_time) in
+        [(date, "YMWD", false), (time.unwrap_or(""), "HMS", true)]
+    {
+        let mut order = 0;
+
+        while !part.is_empty() {
+            // only the last component may have a fraction
+            if fraction {
+                return Err(Error::UnexpectedChar('.'));
+            }
+
+            let end = part
+                .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+                .ok_or(Error::ExpectedUnit)?;
+            let designator = part[end..].chars().next().unwrap_or_default();
+            let num = parse_number(&part[..end])?;
+            part = &part[end + designator.len_utf8()..];
+
+            let unexpected = || Error::UnexpectedUnit(designator.to_string());
+            order += designators[order..]
+                .find(designator)
+                .ok_or_else(unexpected)?
+                + 1;
+            fraction = num.frac.is_some();
+
+            let unit = match (is_time, designator) {
+                (false, 'Y' | 'M') if !calendar => return Err(unexpected()),
+                (false, 'Y' | 'M') => {
+                    let per_unit = if designator == 'Y' { 12 } else { 1 };
+                    nominal.months = whole_quantity(num)?
+                        .checked_mul(per_unit)
+                        .and_then(|n| u32::try_from(n).ok())
+                        .and_then(|n| n.checked_add(nominal.months))
+                        .ok_or(Error::Overflow)?;
+                    continue;
+                }
+                (false, 'W') => DAY * 7,
+                (false, _) => DAY,
+                (true, 'H') => Duration::from_secs(3600),
+                (true, 'M') => Duration::from_secs(60),
+                (true, _) => Duration::from_secs(1),
+            };
+            nominal.fixed = num
+                .checked_mul(unit)
+                .and_then(|d| nominal.fixed.checked_add(d))
+                .ok_or(Error::Overflow)?;
+        }
+    }
+
+    Ok(nominal)
+}
+
+/// Parses a decimal number with a "." or "," as the decimal point.
+fn parse_number(s: &str) -> Result<Quantity, Error> {
+    let (whole, frac) = match s.split_once(['.', ',']) {
+        Some((whole, frac)) => (whole, Some(frac)),
+        None => (s, None),
+    };
+
+    let whole = clock::parse_digits(whole)?;
+    let frac = match frac {
+        Some(frac) => {
+            // digits beyond the ninth are truncated, as in the lexer
+            if let Some(c) = frac.chars().find(|c| !c.is_ascii_digit()) {
+                return Err(Error::UnexpectedChar(c));
+            }
+            let digits = &frac[..frac.len().min(9)];
+            Some(clock::parse_digits(digits)? as u32 * 10u32.pow(9 - digits.len() as u32))
+        }
+        None => None,
+    };
+
+    Ok(Quantity { whole, frac })
+}
+
+/// Parses a date, "YYYY-MM-DD", optionally followed by a time and a UTC
+/// offset, "Thh:mm[:ss[.f]][Z|±hh[:mm]]".
+fn parse_date_time(s: &str) -> Result<SystemTime, Error> {
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+
+    let [year, month, day] = match date.split('-').collect::<Vec<_>>()[..] {
+        [y, m, d] if y.len() == 4 && m.len() == 2 && d.len() == 2 => [y, m, d],
+        _ => return Err(Error::InvalidInterval(s.to_owned())),
+    };
+    let year = clock::parse_digits(year)? as i64;
+    let month = match clock::parse_component(month, 12)? {
+        0 => return Err(Error::OutOfRange(month.to_owned())),
+        m => m as u32,
+    };
+    let day = match clock::parse_component(day, u64::from(days_in_month(year, month)))? {
+        0 => return Err(Error::OutOfRange(day.to_owned())),
+        d => d as u32,
+    };
+
+    let (time, offset) = match time {
+        Some(time) => parse_time(time)?,
+        None => (Duration::ZERO, 0),
+    };
+
+    let days = days_from_civil(year, month, day).ok_or(Error::Overflow)?;
+    let secs = days * 86400 + time.as_secs() as i64 - offset;
+    let t = match u64::try_from(secs) {
+        Ok(secs) => UNIX_EPOCH.checked_add(Duration::from_secs(secs)),
+        Err(_) => UNIX_EPOCH.checked_sub(Duration::from_secs(secs.unsigned_abs())),
+    };
+    t.and_then(|t| t.checked_add(Duration::from_nanos(u64::from(time.subsec_nanos()))))
+        .ok_or(Error::Overflow)
+}
+
+/// Parses a time of day and its UTC offset in seconds.
+fn parse_time(s: &str) -> Result<(Duration, i64), Error> {
+    let (time, offset) = match s.strip_suffix('Z') {
+        Some(time) => (time, 0),
+        None => match s.find(['+', '-']) {
+            Some(i) => {
+                let zone = &s[i + 1..];
+                let (hours, minutes) = match zone.split_once(':') {
+                    Some(hm) => hm,
+                    None if zone.len() > 2 => zone.split_at_checked(2).unwrap_or((zone, "00")),
+                    None => (zone, "00"),
+                };
+                let offset = clock::parse_component(hours, 23)? * 3600
+                    + clock::parse_component(minutes, 59)? * 60;
+                let offset = offset as i64;
+                (
+                    &s[..i],
+                    if s[i..].starts_with('-') {
+                        -offset
+                    } else {
+                        offset
+                    },
+                )
+            }
+            None => (s, 0),
+        },
+    };
+
+    Ok((clock::parse_clock(time, 23, 9)?, offset))
+}
@@ -0,0 +1,264 @@
+/*!
+The lexer shared by the parsers in this crate, which splits a duration string
+into numbers and units.
+
+Tokens borrow from the input and carry their byte span in it, so tools built
+on top of the lexer can point at, highlight or rewrite parts of the input.
+Whitespace, commas and directional marks between tokens are skipped.
+
+## Example
+
+```rust
+use durstr::lexer::{Lexer, TokenKind};
+
+let tokens: Vec<_> = Lexer::new("1h 30 min.").collect::<Result<_, _>>().unwrap();
+assert_eq!(tokens.len(), 4);
+assert_eq!(tokens[1].kind, TokenKind::Unit("h"));
+assert_eq!(tokens[2].kind, TokenKind::Number(30.into()));
+assert_eq!(tokens[3].span, 6..9);
+```
+*/
+
+use core::{iter::Peekable, ops::Range, str::CharIndices, time::Duration};
+
+/// The characters skipped between tokens, besides ASCII whitespace: commas,
+/// including the Arabic comma, and the invisible marks used to control the
+/// direction of mixed right-to-left and left-to-right text.
+pub(crate) const SEPARATORS: &[char] = &[
+    ',', '،', '\u{061C}', '\u{200E}', '\u{200F}', '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}',
+    '\u{202E}', '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+/// The characters accepted as a decimal point, when a digit follows them.
+pub(crate) const DECIMAL_SEPARATORS: &[char] = &['.', '٫'];
+
+/// The characters skipped right after a unit, for abbreviations like "min.".
+pub(crate) const ABBREVIATION_MARKS: &[char] = &['.'];
+
+/// A token, along with its byte span in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind<'a>,
+    /// The byte range of the token in the input. A period after a unit
+    /// ("min.") is not part of it.
+    pub span: Range<usize>,
+}
+
+/// The kind of a [`Token`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind<'a> {
+    Number(Quantity),
+    /// A word, which is usually a unit, but may be any other word of the
+    /// grammar, such as "every".
+    Unit(&'a str),
+}
+
+/// A number in a duration string, with an optional fractional part.
+///
+/// Whole numbers are kept as `u64`, and multiplied with units in `u128`
+/// nanoseconds, so the result is exact down to the nanosecond.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quantity {
+    pub whole: u64,
+    /// The fractional part in billionths, if written with a decimal point.
+    /// Digits beyond the ninth are truncated.
+    pub frac: Option<u32>,
+}
+
+impl From<u64> for Quantity {
+    fn from(whole: u64) -> Self {
+        Quantity { whole, frac: None }
+    }
+}
+
+impl Quantity {
+    #[cfg(feature = "std")]
+    pub(crate) fn is_zero(self) -> bool {
+        self.whole == 0 && self.frac.unwrap_or(0) == 0
+    }
+
+    /// Multiplies `unit` by this quantity, returning `None` on overflow.
+    pub(crate) fn checked_mul(self, unit: Duration) -> Option<Duration> {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+        let unit = unit.as_nanos();
+        let frac = unit * u128::from(self.frac.unwrap_or(0)) / NANOS_PER_SEC;
+        let nanos = u128::from(self.whole)
+            .checked_mul(unit)?
+            .checked_add(frac)?;
+
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+        Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+    }
+
+    /// Divides this many seconds by `rate`, e.g. 30 ticks at 20 ticks per
+    /// second, returning `None` on overflow or a zero rate.
+    ///
+    /// Unlike multiplying by the length of one tick, this is exact for rates
+    /// that don't divide a second evenly.
+    #[cfg(feature = "std")]
+    pub(crate) fn checked_div(self, rate: u32) -> Option<Duration> {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+        let billionths =
+            u128::from(self.whole) * NANOS_PER_SEC + u128::from(self.frac.unwrap_or(0));
+        let nanos = billionths.checked_div(u128::from(rate))?;
+
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+        Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+    }
+}
+
+/// An error that can occur when splitting a duration string into tokens.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum LexError {
+    /// An unexpected character was found.
+    #[error("unexpected character: {0}")]
+    UnexpectedChar(char),
+    /// A number was too large to be represented.
+    #[error("number is too large")]
+    Overflow,
+}
+
+/// Splits a duration string into [`Token`]s.
+///
+/// The lexer is also an iterator of tokens. After an error, it continues
+/// with the input after the offending character or number.
+pub struct Lexer<'a> {
+    source: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    /// Creates a lexer for `source`.
+    pub fn new(source: &'a str) -> Self {
+        Lexer {
+            source,
+            chars: source.char_indices().peekable(),
+        }
+    }
+
+    /// Scans all tokens, dropping their spans.
+    #[cfg(feature = "std")]
+    pub(crate) fn scan_tokens(mut self) -> Result<Vec<TokenKind<'a>>, crate::Error> {
+        let mut tokens = vec![];
+
+        while let Some(token) = self.next_token()? {
+            tokens.push(token.kind);
+        }
+
+        Ok(tokens)
+    }
+
+    /// Returns the next token, or `None` at the end of the input.
+    pub fn next_token(&mut self) -> Result<Option<Token<'a>>, LexError> {
+        while let Some(&(i, c)) = self.chars.peek() {
+            let kind = match c {
+                c if self.should_skip(c) => {
+                    self.chars.next();
+                    continue;
+                }
+                c if digit_value(c).is_some() => TokenKind::Number(self.scan_number()?),
+                c if c.is_alphabetic() => TokenKind::Unit(self.scan_unit(i)),
+                unexpected => {
+                    self.chars.next();
+                    return Err(LexError::UnexpectedChar(unexpected));
+                }
+            };
+
+            let end = match kind {
+                TokenKind::Unit(u) => i + u.len(),
+                TokenKind::Number(_) => self.offset(),
+            };
+            return Ok(Some(Token { kind, span: i..end }));
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the byte offset of the next character.
+    pub(crate) fn offset(&mut self) -> usize {
+        self.chars.peek().map_or(self.source.len(), |&(i, _)| i)
+    }
+
+    /// Whitespace and [`SEPARATORS`] are skipped.
+    fn should_skip(&self, c: char) -> bool {
+        SEPARATORS.contains(&c) || c.is_ascii_whitespace()
+    }
+
+    fn scan_number(&mut self) -> Result<Quantity, LexError> {
+        let mut whole = Some(0u64);
+        while let Some(d) = self.next_digit() {
+            whole = whole
+                .and_then(|n| n.checked_mul(10))
+                .and_then(|n| n.checked_add(u64::from(d)));
+        }
+
+        // a decimal point is only part of the number if a digit follows it
+        let frac = match self.chars.peek() {
+            Some(&(i, c))
+                if DECIMAL_SEPARATORS.contains(&c)
+                    && self.source[i + c.len_utf8()..]
+                        .starts_with(|c| digit_value(c).is_some()) =>
+            {
+                self.chars.next();
+                Some(self.scan_fraction())
+            }
+            _ => None,
+        };
+
+        // the whole number is consumed before reporting an overflow, so the
+        // lexer can continue after it
+        let whole = whole.ok_or(LexError::Overflow)?;
+        Ok(Quantity { whole, frac })
+    }
+
+    /// Scans the digits after a decimal point, in billionths. Digits beyond
+    /// the ninth are truncated.
+    fn scan_fraction(&mut self) -> u32 {
+        let mut frac = 0;
+        let mut scale = 100_000_000;
+        while let Some(d) = self.next_digit() {
+            frac += d * scale;
+            scale /= 10;
+        }
+        frac
+    }
+
+    fn next_digit(&mut self) -> Option<u32> {
+        let (_, c) = self.chars.next_if(|&(_, c)| digit_value(c).is_some())?;
+        digit_value(c)
+    }
+
+    /// Scans a unit, which may contain any alphabetic characters (e.g. "µs").
+    fn scan_unit(&mut self, start: usize) -> &'a str {
+        let mut end = start;
+        while let Some((i, c)) = self.chars.next_if(|(_, c)| c.is_alphabetic()) {
+            end = i + c.len_utf8();
+        }
+
+        // allow abbreviations like "hrs." and "min."
+        self.chars.next_if(|(_, c)| ABBREVIATION_MARKS.contains(c));
+
+        &self.source[start..end]
+    }
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Result<Token<'a>, LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token().transpose()
+    }
+}
+
+/// Returns the value of a decimal digit, which may be an ASCII digit or an
+/// Arabic-Indic digit ("٣" or "۳").
+fn digit_value(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' => Some(c as u32 - '0' as u32),
+        '٠'..='٩' => Some(c as u32 - '٠' as u32),
+        '۰'..='۹' => Some(c as u32 - '۰' as u32),
+        _ => None,
+    }
+}
@@ -11,6 +11,7 @@ struct for more control over parsing behavior.
 The [`parse`] function is a convenience wrapper around a default [`Parser`].
 
 ```rust
+# #[cfg(feature = "std")] {
 use durstr::parse;
 use std::time::Duration;
 
@@ -19,6 +20,7 @@ assert_eq!(dur, Ok(Duration::from_secs(741)));
 
 let dur = parse("1hr 2min 3sec");
 assert_eq!(dur, Ok(Duration::from_secs(3723)));
+# }
 ```
 
 ### The `Parser` struct
@@ -26,6 +28,7 @@ assert_eq!(dur, Ok(Duration::from_secs(3723)));
 For more control, you can use the [`Parser`] struct directly. For example, to parse with case-insensitivity:
 
 ```rust
+# #[cfg(feature = "std")] {
 use durstr::{Parser, ParserOptions};
 use std::time::Duration;
 
@@ -34,6 +37,7 @@ let parser = Parser::new(options);
 
 let dur = parser.parse("1 MINUTE, 2 SECONDS");
 assert_eq!(dur, Ok(Duration::from_secs(62)));
+# }
 ```
 
 ## Units
@@ -50,6 +54,7 @@ By default, the following units are provided:
 You can define your own units, and their values, using the `ParserUnits` struct:
 
 ```rust
+# #[cfg(feature = "std")] {
 use durstr::{Parser, ParserOptions, ParserUnits};
 use std::time::Duration;
 
@@ -63,13 +68,124 @@ let parser = Parser::new(ParserOptions {
 
 let d = parser.parse("4 days");
 assert_eq!(d, Ok(Duration::from_secs(3600) * 24 * 4));
+# }
 ```
 */
 
-use std::{borrow::Cow, collections::HashMap, iter::Peekable, str::CharIndices, time::Duration};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+#[cfg(feature = "parallel")]
+mod batch;
+#[cfg(feature = "business")]
+mod business;
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "calendar")]
+mod calendar;
+#[cfg(feature = "std")]
+mod clause;
+#[cfg(feature = "std")]
+mod clock;
+#[cfg(feature = "constraint")]
+mod constraint;
+#[cfg(feature = "std")]
+mod convert;
+#[cfg(feature = "cst")]
+mod cst;
+#[cfg(feature = "debug")]
+pub mod debug;
+#[cfg(feature = "dotnet")]
+pub mod dotnet;
+#[cfg(feature = "std")]
+mod export;
+#[cfg(feature = "format")]
+mod format;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "format")]
+mod human;
+#[cfg(feature = "std")]
+mod incremental;
+#[cfg(feature = "std")]
+mod info;
+#[cfg(feature = "iso")]
+pub mod iso;
+pub mod lexer;
+#[cfg(feature = "std")]
+mod list;
+#[cfg(feature = "locale")]
+mod locale;
+#[cfg(feature = "nginx")]
+pub mod nginx;
+pub mod no_alloc;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "quantifiers")]
+mod quantifiers;
+#[cfg(feature = "std")]
+mod range;
+#[cfg(feature = "std")]
+mod rate;
+#[cfg(feature = "schedule")]
+mod schedule;
+#[cfg(feature = "sleep")]
+pub mod sleep;
+#[cfg(feature = "std")]
+mod spec;
+#[cfg(feature = "std")]
+mod typos;
+#[cfg(feature = "std")]
+mod unit;
+#[cfg(feature = "std")]
+mod warnings;
+
+#[cfg(feature = "business")]
+pub use business::BusinessSchedule;
+#[cfg(feature = "cache")]
+pub use cache::CachedParser;
+#[cfg(feature = "std")]
+pub use clause::Clause;
+#[cfg(feature = "constraint")]
+pub use constraint::{Comparison, DurationPredicate};
+#[cfg(feature = "std")]
+pub use convert::FromParsedDuration;
+#[cfg(feature = "cst")]
+pub use cst::Cst;
+#[cfg(feature = "std")]
+pub use export::{Spec, UnitSpec};
+#[cfg(feature = "format")]
+pub use format::{Formatter, FormatterOptions, UnitStyle, format};
+#[cfg(feature = "format")]
+pub use human::HumanDuration;
+#[cfg(feature = "std")]
+pub use incremental::IncrementalParser;
+#[cfg(feature = "std")]
+pub use info::ParseInfo;
+#[cfg(feature = "std")]
+use lexer::{LexError, Lexer, Quantity, TokenKind};
+#[cfg(feature = "locale")]
+pub use locale::{Locale, PluralCategory};
+#[cfg(feature = "quantifiers")]
+pub use quantifiers::Quantifiers;
+#[cfg(feature = "std")]
+pub use range::{parse_clock_range, parse_until};
+#[cfg(feature = "schedule")]
+pub use schedule::{Cumulative, Offsets, Schedule};
+#[cfg(feature = "std")]
+pub use unit::Unit;
+#[cfg(feature = "std")]
+pub use warnings::Warning;
 
 /// An error that can occur when parsing a duration string.
-#[derive(thiserror::Error, Debug, PartialEq)]
+#[cfg(feature = "std")]
+#[derive(thiserror::Error, Clone, Debug, PartialEq)]
 pub enum Error {
     /// An unexpected character was found.
     #[error("unexpected character: {0}")]
@@ -83,74 +199,68 @@ pub enum Error {
     /// A number was expected, but not found.
     #[error("expected a number")]
     ExpectedNumber,
+    /// The duration was too large to be represented.
+    #[error("duration is too large")]
+    Overflow,
+    /// A unit smaller than [`ParserOptions::min_unit`] was found.
+    #[error("unit not allowed here: {0}")]
+    UnitTooSmall(String),
+    /// A value was outside the range allowed for it.
+    #[error("value out of range: {0}")]
+    OutOfRange(String),
+    /// An input had more components than [`ParserOptions::max_components`].
+    #[error("too many components, at most {0} are allowed")]
+    TooManyComponents(usize),
+    /// A component was zero, which [`ParserOptions::reject_zero`] forbids.
+    #[error("zero component not allowed: {0}")]
+    ZeroComponent(String),
+    /// A spec string passed to [`ParserOptions::from_spec`] had an invalid item.
+    #[error("invalid option: {0}")]
+    InvalidSpec(String),
+    /// A constraint was missing a comparison operator.
+    #[error("expected a comparison operator (<, <=, >, >=, =)")]
+    ExpectedComparison,
+    /// A schedule was missing "for" followed by its span.
+    #[error("expected \"for\" followed by a duration")]
+    ExpectedScheduleSpan,
+    /// A schedule had a period of zero.
+    #[error("schedule period must not be zero")]
+    ZeroSchedulePeriod,
+    /// A business schedule's holidays left no working day for a whole year.
+    #[error("no working day found within a year")]
+    NoWorkingDay,
+    /// A cumulative phrase was missing "per" followed by its period.
+    #[error("expected \"per\" followed by a period")]
+    ExpectedPeriod,
+    /// A range of times of day was missing the dash between its start and
+    /// end.
+    #[error("expected a range of times, like \"9:00-17:30\"")]
+    ExpectedClockRange,
+    /// A phrase was missing "until" followed by a time of day.
+    #[error("expected \"until\" followed by a time, like \"until 17:30\"")]
+    ExpectedUntil,
+    /// An entry of a map was not a name followed by "=" and a duration.
+    #[error("expected name=duration: {0:?}")]
+    ExpectedMapEntry(String),
+    /// A name appeared more than once in a map.
+    #[error("duplicate name: {0}")]
+    DuplicateMapEntry(String),
+    /// The duration of an entry in a map failed to parse.
+    #[error("invalid duration for {name}: {error}")]
+    InvalidMapEntry { name: String, error: Box<Error> },
+    /// The parts of an ISO 8601 interval didn't make an interval, such as two
+    /// durations, or an end before the start.
+    #[error("invalid interval: {0}")]
+    InvalidInterval(String),
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Token<'a> {
-    Number(u32),
-    Unit(&'a str),
-}
-
-struct Scanner<'a> {
-    source: &'a str,
-    chars: Peekable<CharIndices<'a>>,
-}
-
-impl<'a> Scanner<'a> {
-    fn new(source: &'a str) -> Self {
-        Scanner {
-            source,
-            chars: source.char_indices().peekable(),
-        }
-    }
-
-    fn scan_tokens(mut self) -> Result<Vec<Token<'a>>, Error> {
-        let mut tokens = vec![];
-
-        while let Some(&(i, c)) = self.chars.peek() {
-            match c {
-                c if self.should_skip(c) => {
-                    self.chars.next();
-                }
-                c if c.is_ascii_digit() => {
-                    tokens.push(Token::Number(self.scan_number(i)));
-                }
-                c if c.is_ascii_alphabetic() => {
-                    tokens.push(Token::Unit(self.scan_unit(i)));
-                }
-                unexpected => return Err(Error::UnexpectedChar(unexpected)),
-            };
-        }
-
-        Ok(tokens)
-    }
-
-    fn should_skip(&self, c: char) -> bool {
-        c.is_ascii_whitespace() || c == ','
-    }
-
-    fn scan_number(&mut self, start: usize) -> u32 {
-        let mut end = start;
-        while let Some((_, c)) = self.chars.peek() {
-            if !c.is_ascii_digit() {
-                break;
-            }
-            end = self.chars.next().unwrap().0;
-        }
-
-        self.source[start..=end].parse().unwrap()
-    }
-
-    fn scan_unit(&mut self, start: usize) -> &'a str {
-        let mut end = start;
-        while let Some((_, c)) = self.chars.peek() {
-            if !c.is_ascii_alphabetic() {
-                break;
-            }
-            end = self.chars.next().unwrap().0;
+#[cfg(feature = "std")]
+impl From<LexError> for Error {
+    fn from(e: LexError) -> Self {
+        match e {
+            LexError::UnexpectedChar(c) => Error::UnexpectedChar(c),
+            LexError::Overflow => Error::Overflow,
         }
-
-        &self.source[start..=end]
     }
 }
 
@@ -160,27 +270,35 @@ impl<'a> Scanner<'a> {
 /// ```rust
 /// use durstr::{Parser, ParserOptions, ParserUnits};
 /// use std::time::Duration;
-/// 
+///
 /// let mut units = ParserUnits::default();
 /// units.add_unit("days", Duration::from_secs(3600) * 24);
-/// 
+///
 /// let parser = Parser::new(ParserOptions {
 ///     units,
 ///     ..Default::default()
 /// });
-/// 
+///
 /// let d = parser.parse("4 days");
 /// assert_eq!(d, Ok(Duration::from_secs(3600) * 24 * 4));
 /// ```
+#[cfg(feature = "std")]
 pub struct ParserUnits {
     values: HashMap<&'static str, Duration>,
+    approximate: HashSet<&'static str>,
+    /// The names that stand for one of the units of [`Unit`], as opposed to
+    /// a custom unit that may just have the same length.
+    standard: HashMap<&'static str, Unit>,
 }
 
+#[cfg(feature = "std")]
 impl ParserUnits {
     /// Returns a ParserUnits with no default units (empty map).
     pub fn new() -> Self {
         ParserUnits {
             values: HashMap::new(),
+            approximate: HashSet::new(),
+            standard: HashMap::new(),
         }
     }
 
@@ -196,13 +314,80 @@ impl ParserUnits {
     /// ```
     pub fn add_unit(&mut self, k: &'static str, v: Duration) {
         self.values.insert(k, v);
+        self.approximate.remove(k);
+        self.standard.remove(k);
+    }
+
+    /// Insert/update a name for one of the units of [`Unit`].
+    pub(crate) fn add_standard_unit(&mut self, k: &'static str, unit: Unit) {
+        self.add_unit(k, unit.duration());
+        self.standard.insert(k, unit);
+    }
+
+    /// Insert/update a unit whose value is only an approximation (e.g. a month
+    /// of 30 days).
+    ///
+    /// Parsing works the same as with [`ParserUnits::add_unit`], but
+    /// [`Parser::parse_with_info`] reports when such a unit was used.
+    pub fn add_approximate_unit(&mut self, k: &'static str, v: Duration) {
+        self.values.insert(k, v);
+        self.approximate.insert(k);
+        self.standard.remove(k);
+    }
+
+    /// Insert the colloquial long-span units.
+    ///
+    /// These are not part of the default set, and use fixed conversions (all
+    /// but `fortnight` are approximate):
+    /// - `fortnight(s)`: 14 days
+    /// - `decade(s)`: 10 years of 365.25 days
+    /// - `century`, `centuries`: 100 years of 365.25 days
+    /// - `millennium`, `millennia`, `millenniums`: 1000 years of 365.25 days
+    ///
+    /// ```rust
+    /// use durstr::{Parser, ParserOptions, ParserUnits};
+    /// use std::time::Duration;
+    ///
+    /// let mut units = ParserUnits::default();
+    /// units.add_colloquial_units();
+    ///
+    /// let parser = Parser::new(ParserOptions { units, ..Default::default() });
+    /// assert_eq!(parser.parse("1 fortnight"), Ok(Duration::from_secs(86400 * 14)));
+    /// ```
+    #[cfg(feature = "colloquial")]
+    pub fn add_colloquial_units(&mut self) {
+        const DAY: u64 = 86400;
+        // a Julian year, the usual convention for "decade" and friends
+        const YEAR: u64 = DAY * 36525 / 100;
+
+        for u in ["fortnight", "fortnights"] {
+            self.add_unit(u, Duration::from_secs(DAY * 14));
+        }
+        for u in ["decade", "decades"] {
+            self.add_approximate_unit(u, Duration::from_secs(YEAR * 10));
+        }
+        for u in ["century", "centuries"] {
+            self.add_approximate_unit(u, Duration::from_secs(YEAR * 100));
+        }
+        for u in ["millennium", "millennia", "millenniums"] {
+            self.add_approximate_unit(u, Duration::from_secs(YEAR * 1000));
+        }
     }
 
     fn get_duration(&self, k: &str) -> Option<&Duration> {
         self.values.get(k)
     }
+
+    fn is_approximate(&self, k: &str) -> bool {
+        self.approximate.contains(k)
+    }
+
+    fn get_standard_unit(&self, k: &str) -> Option<Unit> {
+        self.standard.get(k).copied()
+    }
 }
 
+#[cfg(feature = "std")]
 impl Default for ParserUnits {
     /// Provides the default set of units for parsing durations.
     ///
@@ -215,16 +400,16 @@ impl Default for ParserUnits {
         let mut parser_units = ParserUnits::new();
 
         for u in ["h", "hr", "hrs", "hour", "hours"] {
-            parser_units.add_unit(u, Duration::from_secs(3600));
+            parser_units.add_standard_unit(u, Unit::Hour);
         }
         for u in ["m", "min", "mins", "minute", "minutes"] {
-            parser_units.add_unit(u, Duration::from_secs(60));
+            parser_units.add_standard_unit(u, Unit::Minute);
         }
         for u in ["s", "sec", "secs", "second", "seconds"] {
-            parser_units.add_unit(u, Duration::from_secs(1));
+            parser_units.add_standard_unit(u, Unit::Second);
         }
         for u in ["ms", "msec", "msecs", "millisecond", "milliseconds"] {
-            parser_units.add_unit(u, Duration::from_millis(1));
+            parser_units.add_standard_unit(u, Unit::Millisecond);
         }
 
         parser_units
@@ -235,21 +420,59 @@ impl Default for ParserUnits {
 ///
 /// This struct allows for more control over how duration strings are
 /// interpreted. (e.g. enabling case-insensitivity)
+#[cfg(feature = "std")]
 #[derive(Default)]
 pub struct ParserOptions {
+    /// Matches units case-insensitively. Units are case folded, so "İ", "I",
+    /// "ı" and "i" all match a lowercase "i", and "ß" matches "ss".
     pub ignore_case: bool,
     pub units: ParserUnits,
+    /// Allows numbers with a fractional part, like "1.5 hours".
+    pub allow_fractions: bool,
+    /// Rejects units smaller than this, e.g. `Duration::from_secs(60)` to
+    /// only allow minutes and larger.
+    pub min_unit: Option<Duration>,
+    /// Accepts a unit that is one typo away from a known unit, such as
+    /// "minutse" for "minutes", as long as there is only one such unit. See
+    /// [`Parser::parse_with_warnings`] to find out about corrections.
+    pub correct_typos: bool,
+    /// Rejects inputs with more than this many number and unit pairs, e.g.
+    /// "1h 30m" has two. For schedules, the period and span are counted
+    /// separately.
+    pub max_components: Option<usize>,
+    /// Rejects zero components in inputs with more than one, like the "0m"
+    /// in "1h 0m 30s". A lone zero ("0s") is still allowed.
+    pub reject_zero: bool,
+    /// The unit of an input that is just a number, e.g. `"s"` to parse "90"
+    /// as 90 seconds. This must be one of the [`ParserOptions::units`].
+    pub default_unit: Option<&'static str>,
+    /// Enables the "tick" and "ticks" units, with this many ticks per second,
+    /// e.g. 20 for Minecraft-like game servers. Ticks are converted exactly,
+    /// so "30 ticks" at 64 per second is 468.75ms.
+    pub tick_rate: Option<u32>,
+    /// Enables the "sample" and "samples" units, with this many samples per
+    /// second, e.g. 48000 for 48 kHz audio. Like ticks, samples are converted
+    /// exactly.
+    pub sample_rate: Option<u32>,
+    /// Enables business-time units using the given schedule.
+    #[cfg(feature = "business")]
+    pub business: Option<BusinessSchedule>,
+    /// Enables fuzzy quantifiers ("a few minutes") using the given table.
+    #[cfg(feature = "quantifiers")]
+    pub quantifiers: Option<Quantifiers>,
 }
 
 /// A configurable parser for duration strings.
 ///
 /// Use this when you need to configure the parsing logic. Otherwise, the
 /// top-level [`parse`] function is likely sufficient.
+#[cfg(feature = "std")]
 #[derive(Default)]
 pub struct Parser {
     options: ParserOptions,
 }
 
+#[cfg(feature = "std")]
 impl Parser {
     /// Create a new [`Parser`] with provided [`ParserOptions`]
     pub fn new(options: ParserOptions) -> Self {
@@ -258,6 +481,17 @@ impl Parser {
 
     /// Parses a string into a `Duration`, ignoring whitespaces and commas.
     ///
+    /// A period directly after a unit is also ignored, so abbreviations like
+    /// "2 hrs. 30 min." are accepted.
+    ///
+    /// Numbers may have a fractional part ("1.5 hours") if
+    /// [`ParserOptions::allow_fractions`] is set. Results too large for a
+    /// `Duration` are an [`Error::Overflow`].
+    ///
+    /// A leading "every" or "each" is accepted as well, with a bare unit
+    /// meaning one of that unit ("every 5 minutes", "each hour"). Use
+    /// [`Parser::parse_with_info`] to find out whether it was present.
+    ///
     /// Default Units
     /// - `ms`, `msec(s)`, `millisecond(s)`
     /// - `s`, `sec(s)`, `second(s)`
@@ -274,40 +508,185 @@ impl Parser {
     /// assert_eq!(dur, Ok(Duration::from_secs(62)));
     /// ```
     pub fn parse(&self, input: &str) -> Result<Duration, Error> {
-        let tokens = Scanner::new(input).scan_tokens()?;
+        let tokens = self.scan(input)?;
         self.parse_tokens(tokens)
     }
 
-    fn parse_tokens(&self, tokens: Vec<Token>) -> Result<Duration, Error> {
-        let mut tokens = tokens.into_iter();
+    fn scan<'a>(&self, input: &'a str) -> Result<Vec<TokenKind<'a>>, Error> {
+        self.scan_prepared(input).map(|(tokens, _)| tokens)
+    }
+
+    /// Scans `input`, also returning what was found while preparing its
+    /// tokens.
+    fn scan_prepared<'a>(&self, input: &'a str) -> Result<(Vec<TokenKind<'a>>, Prepared), Error> {
+        let tokens = Lexer::new(input).scan_tokens()?;
+        Ok(self.prepare_tokens(input, tokens))
+    }
+
+    /// Rewrites the tokens of `input` before parsing: handles a leading
+    /// "every" or "each", the default unit, quantifiers and business units.
+    #[cfg_attr(not(feature = "business"), allow(unused_variables))]
+    fn prepare_tokens<'a>(
+        &self,
+        input: &'a str,
+        mut tokens: Vec<TokenKind<'a>>,
+    ) -> (Vec<TokenKind<'a>>, Prepared) {
+        let interval = matches!(
+            tokens.first(),
+            Some(TokenKind::Unit(u)) if matches!(self.normalize_unit(u).as_ref(), "every" | "each")
+        );
+        if interval {
+            // "every minute" is "every 1 minute", and a bare "every" is left
+            // in place, so it still needs a number
+            match tokens.get(1) {
+                Some(TokenKind::Unit(_)) => tokens[0] = TokenKind::Number(1.into()),
+                Some(TokenKind::Number(_)) => {
+                    tokens.remove(0);
+                }
+                None => {}
+            }
+        }
+
+        if let (Some(unit), [TokenKind::Number(_)]) = (self.options.default_unit, &tokens[..]) {
+            tokens.push(TokenKind::Unit(unit));
+        }
+
+        #[cfg_attr(not(feature = "quantifiers"), allow(unused_mut))]
+        let mut quantified = false;
+        #[cfg(feature = "quantifiers")]
+        if let Some(quantifiers) = &self.options.quantifiers {
+            (tokens, quantified) = self.replace_quantifiers(quantifiers, tokens);
+        }
+
+        #[cfg(feature = "business")]
+        if self.options.business.is_some() {
+            tokens = self.join_business_units(input, tokens);
+        }
+
+        (
+            tokens,
+            Prepared {
+                interval,
+                quantified,
+            },
+        )
+    }
+
+    fn parse_tokens(&self, tokens: Vec<TokenKind>) -> Result<Duration, Error> {
         let mut dur = Duration::ZERO;
 
+        self.for_each_clause(tokens, |num, unit| {
+            dur = add_durations(dur, self.clause_duration(num, unit)?)?;
+            Ok(())
+        })?;
+
+        Ok(dur)
+    }
+
+    /// Calls `f` with the number and unit of each clause in `tokens`.
+    fn for_each_clause<'a>(
+        &self,
+        tokens: Vec<TokenKind<'a>>,
+        mut f: impl FnMut(Quantity, &'a str) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let components = tokens
+            .iter()
+            .filter(|t| matches!(t, TokenKind::Number(_)))
+            .count();
+        if let Some(max) = self.options.max_components
+            && components > max
+        {
+            return Err(Error::TooManyComponents(max));
+        }
+
+        let mut tokens = tokens.into_iter();
+
         while let Some(token) = tokens.next() {
             let num = match token {
-                Token::Number(n) => n,
-                Token::Unit(_) => return Err(Error::ExpectedNumber),
+                TokenKind::Number(n) => n,
+                TokenKind::Unit(_) => return Err(Error::ExpectedNumber),
             };
 
             let unit = match tokens.next() {
-                Some(Token::Unit(u)) => u,
+                Some(TokenKind::Unit(u)) => u,
                 _ => return Err(Error::ExpectedUnit),
             };
 
-            dur += num * self.get_unit_duration(unit)?;
+            if self.options.reject_zero && components > 1 && num.is_zero() {
+                return Err(Error::ZeroComponent(self.normalize_unit(unit).into_owned()));
+            }
+
+            f(num, unit)?;
         }
 
-        Ok(dur)
+        Ok(())
     }
 
-    fn get_unit_duration(&self, unit: &str) -> Result<Duration, Error> {
-        let unit = if self.options.ignore_case {
-            Cow::Owned(unit.to_lowercase())
+    /// Returns the duration of a clause, `num` of `unit`.
+    fn clause_duration(&self, num: Quantity, unit: &str) -> Result<Duration, Error> {
+        if num.frac.is_some() && !self.options.allow_fractions {
+            return Err(Error::UnexpectedChar('.'));
+        }
+
+        let unit_duration = self.get_unit_duration(unit)?;
+        if let Some(min) = self.options.min_unit
+            && unit_duration < min
+        {
+            return Err(Error::UnitTooSmall(self.normalize_unit(unit).into_owned()));
+        }
+
+        match self.unit_rate(&self.normalize_unit(unit)) {
+            Some(rate) => num.checked_div(rate),
+            None => num.checked_mul(unit_duration),
+        }
+        .ok_or(Error::Overflow)
+    }
+
+    fn normalize_unit<'a>(&self, unit: &'a str) -> Cow<'a, str> {
+        if self.options.ignore_case {
+            Cow::Owned(fold_case(unit))
         } else {
             Cow::Borrowed(unit)
-        };
+        }
+    }
+
+    fn get_unit_duration(&self, unit: &str) -> Result<Duration, Error> {
+        self.lookup_unit(unit).map(|(d, _)| d)
+    }
 
-        match self.options.units.get_duration(&unit) {
-            Some(d) => Ok(*d),
+    /// Returns which of the units of [`Unit`] `unit` names, if any, after
+    /// correcting typos as in [`Parser::lookup_unit`].
+    fn get_standard_unit(&self, unit: &str) -> Result<Option<Unit>, Error> {
+        let (_, corrected) = self.lookup_unit(unit)?;
+        let units = &self.options.units;
+        Ok(match corrected {
+            Some(corrected) => units.get_standard_unit(corrected),
+            None => units.get_standard_unit(&self.normalize_unit(unit)),
+        })
+    }
+
+    /// Looks up the duration of `unit`, also returning the unit it was
+    /// corrected to if it was a typo. See [`ParserOptions::correct_typos`].
+    fn lookup_unit(&self, unit: &str) -> Result<(Duration, Option<&'static str>), Error> {
+        #[cfg(feature = "business")]
+        if let (Some(days), Some(schedule)) =
+            (self.business_unit_days(unit), &self.options.business)
+        {
+            return Ok((days * schedule.workday, None));
+        }
+
+        let unit = self.normalize_unit(unit);
+
+        if let Some(rate) = self.unit_rate(&unit) {
+            return Ok((Duration::from_secs(1) / rate, None));
+        }
+
+        if let Some(d) = self.options.units.get_duration(&unit) {
+            return Ok((*d, None));
+        }
+
+        match self.correct_typo(&unit) {
+            Some(corrected) => Ok((self.options.units.values[corrected], Some(corrected))),
             None => Err(Error::UnexpectedUnit(unit.into_owned())),
         }
     }
@@ -331,6 +710,54 @@ impl Parser {
     // }
 }
 
+/// Case folds `s` for case-insensitive matching against lowercase units.
+///
+/// This is `to_lowercase` plus the full case foldings it lacks, and folds the
+/// Turkish dotted and dotless I to a plain "i", so the same input matches
+/// regardless of the keyboard locale it was typed with.
+#[cfg(feature = "std")]
+fn fold_case(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            'İ' | 'ı' => folded.push('i'),
+            'ς' => folded.push('σ'),
+            'ſ' => folded.push('s'),
+            'ß' | 'ẞ' => folded.push_str("ss"),
+            c => folded.extend(c.to_lowercase()),
+        }
+    }
+
+    folded
+}
+
+/// What [`Parser::prepare_tokens`] found while rewriting the tokens of an
+/// input.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+struct Prepared {
+    /// Whether the input had a leading "every" or "each", marking it as an
+    /// interval.
+    interval: bool,
+    /// Whether a fuzzy quantifier stood in for a number.
+    quantified: bool,
+}
+
+#[cfg(feature = "std")]
+fn add_durations(a: Duration, b: Duration) -> Result<Duration, Error> {
+    a.checked_add(b).ok_or(Error::Overflow)
+}
+
+/// Returns a whole quantity, for units that can't be fractional.
+#[cfg(feature = "calendar")]
+fn whole_quantity(num: Quantity) -> Result<u64, Error> {
+    match num.frac {
+        Some(_) => Err(Error::UnexpectedChar('.')),
+        None => Ok(num.whole),
+    }
+}
+
 /// Parses a duration string into a `std::time::Duration`.
 ///
 /// This function provides a quick and easy way to parse common duration
@@ -355,34 +782,187 @@ impl Parser {
 /// let dur = parse("1 MINUTE");
 /// assert!(dur.is_err());
 /// ```
+#[cfg(feature = "std")]
 pub fn parse(input: &str) -> Result<Duration, Error> {
     Parser::default().parse(input)
 }
 
-#[cfg(test)]
+/// Parses a duration string into any type implementing [`FromParsedDuration`].
+///
+/// This is a convenience wrapper around [`Parser::parse_into`] with a default
+/// [`Parser`].
+#[cfg(feature = "std")]
+pub fn parse_into<T: FromParsedDuration>(input: &str) -> Result<T, Error> {
+    Parser::default().parse_into(input)
+}
+
+/// Parses a duration string, also returning [`Warning`]s about anything
+/// questionable in it.
+///
+/// This is a convenience wrapper around [`Parser::parse_with_warnings`] with a
+/// default [`Parser`].
+#[cfg(feature = "std")]
+pub fn parse_with_warnings(input: &str) -> Result<(Duration, Vec<Warning>), Error> {
+    Parser::default().parse_with_warnings(input)
+}
+
+/// Parses a constraint string into a [`DurationPredicate`].
+///
+/// This is a convenience wrapper around [`Parser::parse_constraint`] with a
+/// default [`Parser`].
+#[cfg(feature = "constraint")]
+pub fn parse_constraint(input: &str) -> Result<DurationPredicate, Error> {
+    Parser::default().parse_constraint(input)
+}
+
+/// Parses a duration string into a lossless [`Cst`].
+///
+/// This is a convenience wrapper around [`Parser::parse_cst`] with a default
+/// [`Parser`].
+#[cfg(feature = "cst")]
+pub fn parse_cst(input: &str) -> Result<Cst<'_>, Error> {
+    Parser::default().parse_cst(input)
+}
+
+/// Parses a duration string into its [`Clause`]s.
+///
+/// This is a convenience wrapper around [`Parser::parse_clauses`] with a
+/// default [`Parser`].
+#[cfg(feature = "std")]
+pub fn parse_clauses(input: &str) -> Result<Vec<Clause<'_>>, Error> {
+    Parser::default().parse_clauses(input)
+}
+
+/// Parses a comma-separated list of durations.
+///
+/// This is a convenience wrapper around [`Parser::parse_list`] with a default
+/// [`Parser`].
+#[cfg(feature = "std")]
+pub fn parse_list(input: &str) -> Result<Vec<Duration>, Error> {
+    Parser::default().parse_list(input)
+}
+
+/// Parses a `;`-separated list of named durations.
+///
+/// This is a convenience wrapper around [`Parser::parse_map`] with a default
+/// [`Parser`].
+#[cfg(feature = "std")]
+pub fn parse_map(input: &str) -> Result<Vec<(&str, Duration)>, Error> {
+    Parser::default().parse_map(input)
+}
+
+/// Parses a schedule string into a [`Schedule`].
+///
+/// This is a convenience wrapper around [`Parser::parse_schedule`] with a
+/// default [`Parser`].
+///
+/// ## Examples
+/// ```
+/// use durstr::parse_schedule;
+/// use std::time::Duration;
+///
+/// let schedule = parse_schedule("every 30s for 2min").unwrap();
+/// assert_eq!(schedule.offsets().last(), Some(Duration::from_secs(90)));
+/// ```
+#[cfg(feature = "schedule")]
+pub fn parse_schedule(input: &str) -> Result<Schedule, Error> {
+    Parser::default().parse_schedule(input)
+}
+
+/// Parses a cumulative phrase into a [`Cumulative`].
+///
+/// This is a convenience wrapper around [`Parser::parse_cumulative`] with a
+/// default [`Parser`].
+#[cfg(feature = "schedule")]
+pub fn parse_cumulative(input: &str) -> Result<Cumulative, Error> {
+    Parser::default().parse_cumulative(input)
+}
+
+/// Parses many duration strings in parallel.
+///
+/// This is a convenience wrapper around [`Parser::parse_batch`] with a
+/// default [`Parser`].
+#[cfg(feature = "parallel")]
+pub fn parse_batch(inputs: &[&str]) -> Vec<Result<Duration, Error>> {
+    Parser::default().parse_batch(inputs)
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use crate::{Scanner, Token};
+    use crate::{
+        fold_case,
+        lexer::{Lexer, Quantity, TokenKind},
+    };
+
+    #[test]
+    fn test_fold_case() {
+        assert_eq!(fold_case("MINUTES"), "minutes");
+        assert_eq!(fold_case("DAKİKA"), "dakika");
+        assert_eq!(fold_case("DAKIKA"), "dakika");
+        assert_eq!(fold_case("dakıka"), "dakika");
+        assert_eq!(fold_case("ΛΕΠΤΆ"), "λεπτά");
+        assert_eq!(fold_case("ωρες"), "ωρεσ");
+        assert_eq!(fold_case("STUNDE"), "stunde");
+        assert_eq!(fold_case("Maß"), "mass");
+    }
 
     #[test]
     fn test_scanner() {
-        let scanner = Scanner::new("10 seconds");
+        let scanner = Lexer::new("10 seconds");
         let tokens = scanner.scan_tokens();
-        assert_eq!(tokens, Ok(vec![Token::Number(10), Token::Unit("seconds")]));
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                TokenKind::Number(10.into()),
+                TokenKind::Unit("seconds")
+            ])
+        );
+
+        let scanner = Lexer::new("9hr1min");
+        let tokens = scanner.scan_tokens();
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                TokenKind::Number(9.into()),
+                TokenKind::Unit("hr"),
+                TokenKind::Number(1.into()),
+                TokenKind::Unit("min"),
+            ])
+        );
+
+        let scanner = Lexer::new("2 hrs. 30 min.");
+        let tokens = scanner.scan_tokens();
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                TokenKind::Number(2.into()),
+                TokenKind::Unit("hrs"),
+                TokenKind::Number(30.into()),
+                TokenKind::Unit("min"),
+            ])
+        );
 
-        let scanner = Scanner::new("9hr1min");
+        let scanner = Lexer::new("2.3µs");
         let tokens = scanner.scan_tokens();
         assert_eq!(
             tokens,
             Ok(vec![
-                Token::Number(9),
-                Token::Unit("hr"),
-                Token::Number(1),
-                Token::Unit("min"),
+                TokenKind::Number(Quantity {
+                    whole: 2,
+                    frac: Some(300_000_000)
+                }),
+                TokenKind::Unit("µs"),
             ])
         );
 
-        let scanner = Scanner::new("712635 days");
+        let scanner = Lexer::new("712635 days");
         let tokens = scanner.scan_tokens();
-        assert_eq!(tokens, Ok(vec![Token::Number(712635), Token::Unit("days")]));
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                TokenKind::Number(712635.into()),
+                TokenKind::Unit("days")
+            ])
+        );
     }
 }
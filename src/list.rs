@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use crate::{Error, Parser};
+
+impl Parser {
+    /// Parses a comma-separated list of durations, such as a retry schedule
+    /// ("1s, 5s, 30s, 2m").
+    ///
+    /// Each item is parsed with [`Parser::parse`], so it may have several
+    /// components ("1m 30s"), but no commas. An empty or blank input is an
+    /// empty list, while an empty item is an [`Error::ExpectedNumber`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::Parser;
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    /// let list = parser.parse_list("1s, 5s, 1m 30s");
+    /// assert_eq!(list, Ok([1, 5, 90].map(Duration::from_secs).to_vec()));
+    /// ```
+    pub fn parse_list(&self, input: &str) -> Result<Vec<Duration>, Error> {
+        if input.trim().is_empty() {
+            return Ok(vec![]);
+        }
+
+        input
+            .split(',')
+            .map(|item| match item.trim() {
+                "" => Err(Error::ExpectedNumber),
+                item => self.parse(item),
+            })
+            .collect()
+    }
+
+    /// Parses a `;`-separated list of named durations, such as a bundle of
+    /// timeouts ("connect=5s; read=30s; total=2m").
+    ///
+    /// The entries are returned in order. Each one is a name and a duration
+    /// separated by "=", with whitespace around either trimmed, and each
+    /// name may only appear once. An empty or blank input is an empty list,
+    /// and a trailing ";" is ignored.
+    ///
+    /// Errors name the entry they are about: an entry without a name or "="
+    /// is an [`Error::ExpectedMapEntry`], and a duration that fails to parse
+    /// is an [`Error::InvalidMapEntry`] wrapping the error. Only the first
+    /// error is returned; use [`Parser::parse_map_entries`] to report all of
+    /// them.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::{Error, Parser};
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    /// let map = parser.parse_map("connect=5s; read=30s;").unwrap();
+    /// assert_eq!(map, [("connect", Duration::from_secs(5)), ("read", Duration::from_secs(30))]);
+    ///
+    /// let map = parser.parse_map("connect=5s; read=30x");
+    /// assert_eq!(
+    ///     map,
+    ///     Err(Error::InvalidMapEntry {
+    ///         name: "read".to_owned(),
+    ///         error: Box::new(Error::UnexpectedUnit("x".to_owned())),
+    ///     })
+    /// );
+    /// ```
+    pub fn parse_map<'a>(&self, input: &'a str) -> Result<Vec<(&'a str, Duration)>, Error> {
+        self.parse_map_entries(input).into_iter().collect()
+    }
+
+    /// Parses a `;`-separated list of named durations like
+    /// [`Parser::parse_map`], but parses every entry, returning the result of
+    /// each one in order, for validating configs with several mistakes.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::{Error, Parser};
+    ///
+    /// let parser = Parser::default();
+    /// let entries = parser.parse_map_entries("connect=5x; read=30s; read=1m");
+    ///
+    /// let errors: Vec<_> = entries.into_iter().filter_map(Result::err).collect();
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors[1], Error::DuplicateMapEntry("read".to_owned()));
+    /// ```
+    pub fn parse_map_entries<'a>(&self, input: &'a str) -> Vec<Result<(&'a str, Duration), Error>> {
+        let input = input.trim();
+        let input = input.strip_suffix(';').unwrap_or(input);
+        if input.trim().is_empty() {
+            return vec![];
+        }
+
+        let mut names = vec![];
+        input
+            .split(';')
+            .map(|entry| {
+                let (name, value) = match entry.split_once('=') {
+                    Some((name, value)) if !name.trim().is_empty() => (name.trim(), value),
+                    _ => return Err(Error::ExpectedMapEntry(entry.trim().to_owned())),
+                };
+                if names.contains(&name) {
+                    return Err(Error::DuplicateMapEntry(name.to_owned()));
+                }
+                names.push(name);
+
+                let d = match value.trim() {
+                    "" => Err(Error::ExpectedNumber),
+                    value => self.parse(value),
+                };
+                let d = d.map_err(|e| Error::InvalidMapEntry {
+                    name: name.to_owned(),
+                    error: Box::new(e),
+                })?;
+                Ok((name, d))
+            })
+            .collect()
+    }
+}
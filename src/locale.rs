@@ -0,0 +1,218 @@
+#[cfg(all(feature = "sys-locale", feature = "format"))]
+use crate::{Formatter, FormatterOptions};
+#[cfg(feature = "sys-locale")]
+use crate::{Parser, ParserOptions};
+use crate::{ParserUnits, Unit};
+
+/// A language for the words of units.
+///
+/// ## Example
+/// ```rust
+/// use durstr::{Locale, Parser, ParserOptions, ParserUnits};
+/// use std::time::Duration;
+///
+/// let parser = Parser::new(ParserOptions {
+///     units: ParserUnits::for_locale(Locale::Arabic),
+///     ..Default::default()
+/// });
+///
+/// assert_eq!(parser.parse("١ ساعة، ٣٠ دقيقة"), Ok(Duration::from_secs(5400)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    Arabic,
+    Hebrew,
+    Polish,
+    Russian,
+}
+
+/// A CLDR plural category, which selects the form of a word that goes with a
+/// number.
+///
+/// Which categories exist, and which numbers fall in them, depends on the
+/// language. English only has `One` and `Other`, while Russian uses `One`
+/// for 21 ("21 минута"), `Few` for 2 ("2 минуты") and `Many` for 5
+/// ("5 минут").
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl Locale {
+    /// Returns the locale of a POSIX locale name ("ru_RU.UTF-8") or BCP 47
+    /// language tag ("he-IL"), going by its language only, or `None` if the
+    /// language isn't supported.
+    ///
+    /// ```rust
+    /// use durstr::Locale;
+    ///
+    /// assert_eq!(Locale::from_tag("pl_PL.UTF-8"), Some(Locale::Polish));
+    /// assert_eq!(Locale::from_tag("ar-EG"), Some(Locale::Arabic));
+    /// assert_eq!(Locale::from_tag("C"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.split(['_', '-', '.', '@']).next()?;
+
+        match language.to_ascii_lowercase().as_str() {
+            "en" => Some(Locale::English),
+            "ar" => Some(Locale::Arabic),
+            // "iw" is the deprecated code still used by some systems
+            "he" | "iw" => Some(Locale::Hebrew),
+            "pl" => Some(Locale::Polish),
+            "ru" => Some(Locale::Russian),
+            _ => None,
+        }
+    }
+
+    /// Returns the locale of the system, from the first of the `LC_ALL`,
+    /// `LC_MESSAGES` and `LANG` environment variables that is set, as on
+    /// POSIX systems. Unsupported languages, and systems without these
+    /// variables, fall back to English.
+    #[cfg(feature = "sys-locale")]
+    pub fn system() -> Locale {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|tag| !tag.is_empty())
+            .and_then(|tag| Locale::from_tag(&tag))
+            .unwrap_or_default()
+    }
+
+    /// Returns the plural category of the whole number `n`, following the
+    /// CLDR plural rules of this locale.
+    ///
+    /// ```rust
+    /// use durstr::{Locale, PluralCategory};
+    ///
+    /// assert_eq!(Locale::Russian.plural_category(22), PluralCategory::Few);
+    /// assert_eq!(Locale::Polish.plural_category(22), PluralCategory::Few);
+    /// assert_eq!(Locale::Polish.plural_category(21), PluralCategory::Many);
+    /// ```
+    pub fn plural_category(self, n: u128) -> PluralCategory {
+        let (n10, n100) = (n % 10, n % 100);
+        let few = (2..=4).contains(&n10) && !(12..=14).contains(&n100);
+
+        match self {
+            Locale::English => match n {
+                1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            Locale::Arabic => match n {
+                0 => PluralCategory::Zero,
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                _ if (3..=10).contains(&n100) => PluralCategory::Few,
+                _ if (11..=99).contains(&n100) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            Locale::Hebrew => match n {
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                _ => PluralCategory::Other,
+            },
+            Locale::Polish => match n {
+                1 => PluralCategory::One,
+                _ if few => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            Locale::Russian => match n {
+                _ if n10 == 1 && n100 != 11 => PluralCategory::One,
+                _ if few => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+        }
+    }
+}
+
+impl ParserUnits {
+    /// Returns the default units, plus the words for milliseconds, seconds,
+    /// minutes and hours in `locale`, in all their plural forms.
+    ///
+    /// Right-to-left text is matched in logical order, so the number comes
+    /// before the unit as it is typed ("٥ دقائق", "5 דקות"), and directional
+    /// marks around the digits are ignored. Common spellings without
+    /// hamza or with a final "ه" for "ة" are accepted in Arabic, as are both
+    /// spellings of "שנייה" in Hebrew.
+    pub fn for_locale(locale: Locale) -> Self {
+        let mut units = ParserUnits::default();
+
+        let (millis, seconds, minutes, hours): (&[&str], &[&str], &[&str], &[&str]) = match locale {
+            Locale::English => (&[], &[], &[], &[]),
+            Locale::Arabic => (
+                &[],
+                &["ثانية", "ثانيه", "ثانيتان", "ثانيتين", "ثوان", "ثواني"],
+                &["دقيقة", "دقيقه", "دقيقتان", "دقيقتين", "دقائق", "دقايق"],
+                &["ساعة", "ساعه", "ساعتان", "ساعتين", "ساعات"],
+            ),
+            Locale::Hebrew => (
+                &["מילישנייה", "מילישניות"],
+                &["שנייה", "שניה", "שניות"],
+                &["דקה", "דקות"],
+                &["שעה", "שעות"],
+            ),
+            Locale::Polish => (
+                &["milisekunda", "milisekundy", "milisekund", "milisekundę"],
+                &["sekunda", "sekundy", "sekund", "sekundę"],
+                &["minuta", "minuty", "minut", "minutę"],
+                &["godzina", "godziny", "godzin", "godzinę"],
+            ),
+            Locale::Russian => (
+                &[
+                    "миллисекунда",
+                    "миллисекунды",
+                    "миллисекунд",
+                    "миллисекунду",
+                ],
+                &["секунда", "секунды", "секунд", "секунду"],
+                &["минута", "минуты", "минут", "минуту"],
+                &["час", "часа", "часов"],
+            ),
+        };
+
+        for &u in millis {
+            units.add_standard_unit(u, Unit::Millisecond);
+        }
+        for &u in seconds {
+            units.add_standard_unit(u, Unit::Second);
+        }
+        for &u in minutes {
+            units.add_standard_unit(u, Unit::Minute);
+        }
+        for &u in hours {
+            units.add_standard_unit(u, Unit::Hour);
+        }
+
+        units
+    }
+}
+
+#[cfg(feature = "sys-locale")]
+impl Parser {
+    /// Creates a [`Parser`] with the unit words of the [system
+    /// locale](Locale::system), in addition to the default units.
+    pub fn system_locale() -> Self {
+        Parser::new(ParserOptions {
+            units: ParserUnits::for_locale(Locale::system()),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(all(feature = "sys-locale", feature = "format"))]
+impl Formatter {
+    /// Creates a [`Formatter`] that writes unit names in the [system
+    /// locale](Locale::system).
+    pub fn system_locale() -> Self {
+        Formatter::new(FormatterOptions {
+            locale: Locale::system(),
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,116 @@
+/*!
+Compatibility with the time values of nginx configs, like "1h30m".
+
+## Example
+
+```rust
+use durstr::nginx;
+use std::time::Duration;
+
+let dur = nginx::parse("1h 30m");
+assert_eq!(dur, Ok(Duration::from_secs(5400)));
+
+let dur = nginx::parse("2M");
+assert_eq!(dur, Ok(Duration::from_secs(86400 * 60)));
+
+let dur = nginx::parse("90");
+assert_eq!(dur, Ok(Duration::from_secs(90)));
+```
+*/
+
+use std::{sync::LazyLock, time::Duration};
+
+use crate::{
+    Error, Parser, ParserOptions, ParserUnits, Unit,
+    lexer::{Lexer, TokenKind},
+};
+
+static PARSER: LazyLock<Parser> = LazyLock::new(|| {
+    Parser::new(ParserOptions {
+        units: ParserUnits::nginx(),
+        ..Default::default()
+    })
+});
+
+impl ParserUnits {
+    /// Returns the units used in nginx time values.
+    ///
+    /// Like nginx, a month is 30 days and a year is 365 days, both of which
+    /// are approximate. Units are case-sensitive, with `m` for minutes and
+    /// `M` for months.
+    ///
+    /// Units
+    /// - `ms`
+    /// - `s`
+    /// - `m`
+    /// - `h`
+    /// - `d`
+    /// - `w`
+    /// - `M`
+    /// - `y`
+    pub fn nginx() -> Self {
+        const DAY: u64 = 86400;
+        let mut units = ParserUnits::new();
+
+        units.add_standard_unit("ms", Unit::Millisecond);
+        units.add_standard_unit("s", Unit::Second);
+        units.add_standard_unit("m", Unit::Minute);
+        units.add_standard_unit("h", Unit::Hour);
+        units.add_unit("d", Duration::from_secs(DAY));
+        units.add_unit("w", Duration::from_secs(DAY * 7));
+        units.add_approximate_unit("M", Duration::from_secs(DAY * 30));
+        units.add_approximate_unit("y", Duration::from_secs(DAY * 365));
+
+        units
+    }
+}
+
+/// Parses an nginx time value into a `Duration`, following the rules of
+/// nginx's `ngx_parse_time`.
+///
+/// Components use the units of [`ParserUnits::nginx`] and may be separated
+/// by whitespace ("1h30m", "1h 30m"), but not by commas. Each unit may only
+/// appear once, from the most to the least significant, otherwise it is an
+/// [`Error::UnexpectedUnit`]. A final number without a unit is in seconds
+/// ("1m30" is 90 seconds), and fractions are not allowed.
+pub fn parse(input: &str) -> Result<Duration, Error> {
+    if let Some(c) = input
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !c.is_ascii_whitespace())
+    {
+        return Err(Error::UnexpectedChar(c));
+    }
+
+    let units = &PARSER.options.units;
+    let mut lexer = Lexer::new(input).map(|t| t.map(|t| t.kind));
+    let mut total = Duration::ZERO;
+    let mut last_unit = None;
+
+    while let Some(token) = lexer.next().transpose()? {
+        let num = match token {
+            TokenKind::Number(n) => n,
+            TokenKind::Unit(_) => return Err(Error::ExpectedNumber),
+        };
+
+        let (unit, unit_duration) = match lexer.next().transpose()? {
+            Some(TokenKind::Unit(u)) => {
+                let d = units.get_duration(u).copied();
+                (u, d.ok_or_else(|| Error::UnexpectedUnit(u.to_owned()))?)
+            }
+            None => ("s", Duration::from_secs(1)),
+            Some(TokenKind::Number(_)) => return Err(Error::ExpectedUnit),
+        };
+
+        if last_unit.is_some_and(|last| unit_duration >= last) {
+            return Err(Error::UnexpectedUnit(unit.to_owned()));
+        }
+        last_unit = Some(unit_duration);
+
+        total = num
+            .checked_mul(unit_duration)
+            .and_then(|d| total.checked_add(d))
+            .ok_or(Error::Overflow)?;
+    }
+
+    Ok(total)
+}
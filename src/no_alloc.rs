@@ -0,0 +1,156 @@
+/*!
+Allocation-free parsing, for `no_std` targets without `alloc`.
+
+This module is always available, and is the only parser when the `std`
+feature is disabled. Input is parsed in a single streaming pass, so nothing is
+buffered and there is no capacity to run out of.
+
+It supports the core grammar of numbers followed by units, with units looked
+up in a caller-provided table. The other parts of the grammar, such as
+quantifiers or "every", require the `std` feature.
+
+## Example
+
+```rust
+use durstr::no_alloc;
+use core::time::Duration;
+
+let dur = no_alloc::parse("1h 2min 3s");
+assert_eq!(dur, Ok(Duration::from_secs(3723)));
+
+let dur = no_alloc::parse("2 days");
+assert_eq!(dur, Err(no_alloc::Error::UnexpectedUnit("days")));
+```
+*/
+
+use core::time::Duration;
+
+use crate::lexer::{LexError, Lexer, TokenKind};
+
+/// The default units, the same as the default `ParserUnits`.
+pub const DEFAULT_UNITS: &[(&str, Duration)] = &[
+    ("h", Duration::from_secs(3600)),
+    ("hr", Duration::from_secs(3600)),
+    ("hrs", Duration::from_secs(3600)),
+    ("hour", Duration::from_secs(3600)),
+    ("hours", Duration::from_secs(3600)),
+    ("m", Duration::from_secs(60)),
+    ("min", Duration::from_secs(60)),
+    ("mins", Duration::from_secs(60)),
+    ("minute", Duration::from_secs(60)),
+    ("minutes", Duration::from_secs(60)),
+    ("s", Duration::from_secs(1)),
+    ("sec", Duration::from_secs(1)),
+    ("secs", Duration::from_secs(1)),
+    ("second", Duration::from_secs(1)),
+    ("seconds", Duration::from_secs(1)),
+    ("ms", Duration::from_millis(1)),
+    ("msec", Duration::from_millis(1)),
+    ("msecs", Duration::from_millis(1)),
+    ("millisecond", Duration::from_millis(1)),
+    ("milliseconds", Duration::from_millis(1)),
+];
+
+/// An error that can occur when parsing a duration string without allocating.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum Error<'a> {
+    /// An unexpected character was found.
+    #[error("unexpected character: {0}")]
+    UnexpectedChar(char),
+    /// An unexpected unit was found.
+    #[error("unexpected unit: {0}")]
+    UnexpectedUnit(&'a str),
+    /// A unit was expected, but not found.
+    #[error("expected a unit")]
+    ExpectedUnit,
+    /// A number was expected, but not found.
+    #[error("expected a number")]
+    ExpectedNumber,
+    /// The duration was too large to be represented.
+    #[error("duration is too large")]
+    Overflow,
+}
+
+impl From<LexError> for Error<'_> {
+    fn from(e: LexError) -> Self {
+        match e {
+            LexError::UnexpectedChar(c) => Error::UnexpectedChar(c),
+            LexError::Overflow => Error::Overflow,
+        }
+    }
+}
+
+/// An allocation-free parser for duration strings.
+///
+/// ## Example
+/// ```rust
+/// use durstr::no_alloc::Parser;
+/// use core::time::Duration;
+///
+/// const UNITS: &[(&str, Duration)] = &[("t", Duration::from_millis(50))];
+///
+/// let parser = Parser { units: UNITS, ignore_case: true };
+/// assert_eq!(parser.parse("20 T"), Ok(Duration::from_secs(1)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Parser<'u> {
+    /// The units and their values.
+    pub units: &'u [(&'u str, Duration)],
+    /// Whether to match units case-insensitively. Only ASCII letters are
+    /// folded.
+    pub ignore_case: bool,
+}
+
+impl Default for Parser<'static> {
+    /// A case-sensitive parser with the [`DEFAULT_UNITS`].
+    fn default() -> Self {
+        Parser {
+            units: DEFAULT_UNITS,
+            ignore_case: false,
+        }
+    }
+}
+
+impl Parser<'_> {
+    /// Parses a string into a `Duration`, ignoring whitespaces and commas.
+    pub fn parse<'a>(&self, input: &'a str) -> Result<Duration, Error<'a>> {
+        let mut lexer = Lexer::new(input);
+        let mut dur = Duration::ZERO;
+
+        while let Some(token) = lexer.next_token()? {
+            let num = match token.kind {
+                TokenKind::Number(n) if n.frac.is_some() => return Err(Error::UnexpectedChar('.')),
+                TokenKind::Number(n) => n,
+                TokenKind::Unit(_) => return Err(Error::ExpectedNumber),
+            };
+
+            let unit = match lexer.next_token()?.map(|t| t.kind) {
+                Some(TokenKind::Unit(u)) => u,
+                _ => return Err(Error::ExpectedUnit),
+            };
+
+            dur = num
+                .checked_mul(self.get_unit_duration(unit)?)
+                .and_then(|d| dur.checked_add(d))
+                .ok_or(Error::Overflow)?;
+        }
+
+        Ok(dur)
+    }
+
+    fn get_unit_duration<'a>(&self, unit: &'a str) -> Result<Duration, Error<'a>> {
+        self.units
+            .iter()
+            .find(|(k, _)| match self.ignore_case {
+                true => k.eq_ignore_ascii_case(unit),
+                false => *k == unit,
+            })
+            .map(|&(_, d)| d)
+            .ok_or(Error::UnexpectedUnit(unit))
+    }
+}
+
+/// Parses a duration string with a default [`Parser`], without allocating.
+pub fn parse(input: &str) -> Result<Duration, Error<'_>> {
+    Parser::default().parse(input)
+}
@@ -0,0 +1,154 @@
+/*!
+Compatibility with PostgreSQL `interval` strings.
+
+## Example
+
+```rust
+use durstr::postgres;
+use std::time::Duration;
+
+let dur = postgres::parse_interval("1 day 02:03:04");
+assert_eq!(dur, Ok(Duration::from_secs(93784)));
+
+let dur = postgres::parse_interval("@ 1 hour 30 mins");
+assert_eq!(dur, Ok(Duration::from_secs(5400)));
+
+assert_eq!(postgres::format_interval(Duration::from_secs(93784)), "1 day 02:03:04");
+```
+*/
+
+use std::{sync::LazyLock, time::Duration};
+
+use crate::{Error, Parser, ParserOptions, ParserUnits, Unit, add_durations, clock::parse_clock};
+
+static PARSER: LazyLock<Parser> = LazyLock::new(|| {
+    Parser::new(ParserOptions {
+        units: ParserUnits::postgres(),
+        allow_fractions: true,
+        ..Default::default()
+    })
+});
+
+impl ParserUnits {
+    /// Returns the units used in PostgreSQL intervals.
+    ///
+    /// Like PostgreSQL, a month is 30 days and a year is 365.25 days, both
+    /// of which are approximate.
+    ///
+    /// Units
+    /// - `microsecond(s)`, `us`
+    /// - `millisecond(s)`, `msec(s)`, `ms`
+    /// - `second(s)`, `sec(s)`, `s`
+    /// - `minute(s)`, `min(s)`, `m`
+    /// - `hour(s)`, `hr(s)`, `h`
+    /// - `day(s)`, `d`
+    /// - `week(s)`, `w`
+    /// - `month(s)`, `mon(s)`
+    /// - `year(s)`, `yr(s)`, `y`
+    pub fn postgres() -> Self {
+        const DAY: u64 = 86400;
+        let mut units = ParserUnits::new();
+
+        for u in ["us", "microsecond", "microseconds"] {
+            units.add_unit(u, Duration::from_micros(1));
+        }
+        for u in ["ms", "msec", "msecs", "millisecond", "milliseconds"] {
+            units.add_standard_unit(u, Unit::Millisecond);
+        }
+        for u in ["s", "sec", "secs", "second", "seconds"] {
+            units.add_standard_unit(u, Unit::Second);
+        }
+        for u in ["m", "min", "mins", "minute", "minutes"] {
+            units.add_standard_unit(u, Unit::Minute);
+        }
+        for u in ["h", "hr", "hrs", "hour", "hours"] {
+            units.add_standard_unit(u, Unit::Hour);
+        }
+        for u in ["d", "day", "days"] {
+            units.add_unit(u, Duration::from_secs(DAY));
+        }
+        for u in ["w", "week", "weeks"] {
+            units.add_unit(u, Duration::from_secs(DAY * 7));
+        }
+        for u in ["mon", "mons", "month", "months"] {
+            units.add_approximate_unit(u, Duration::from_secs(DAY * 30));
+        }
+        for u in ["y", "yr", "yrs", "year", "years"] {
+            units.add_approximate_unit(u, Duration::from_secs(DAY * 36525 / 100));
+        }
+
+        units
+    }
+}
+
+/// Parses a PostgreSQL `interval` string into a `Duration`.
+///
+/// This accepts the `postgres` output style ("3 mons 10 days 02:03:04") and
+/// the `postgres_verbose` style ("@ 1 hour 30 mins"), using the units of
+/// [`ParserUnits::postgres`]. Negative intervals, including ones with "ago",
+/// are not supported, as a `Duration` can't be negative.
+pub fn parse_interval(input: &str) -> Result<Duration, Error> {
+    let input = input.trim();
+    let input = input.strip_prefix('@').unwrap_or(input);
+
+    let mut clock = Duration::ZERO;
+    let mut rest = String::with_capacity(input.len());
+
+    for word in input.split_whitespace() {
+        let word = word.strip_prefix('+').unwrap_or(word);
+        if word.contains(':') {
+            clock = add_durations(clock, parse_clock(word, u64::MAX, 6)?)?;
+        } else {
+            rest.push_str(word);
+            rest.push(' ');
+        }
+    }
+
+    add_durations(PARSER.parse(&rest)?, clock)
+}
+
+/// Formats a `Duration` as a PostgreSQL `interval` literal, in the `postgres`
+/// output style ("1 day 02:03:04").
+///
+/// Days are only included when non-zero, and the time only when non-zero or
+/// when there are no days. Fractional seconds are included with up to
+/// microsecond precision, which is the precision of PostgreSQL intervals. The
+/// output only contains digits, letters, spaces, ':' and '.', so it can be
+/// placed in a quoted SQL literal as is.
+///
+/// ## Examples
+/// ```
+/// use durstr::postgres::format_interval;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_interval(Duration::from_secs(86400 * 3)), "3 days");
+/// assert_eq!(format_interval(Duration::from_millis(1500)), "00:00:01.5");
+/// ```
+pub fn format_interval(d: Duration) -> String {
+    let secs = d.as_secs();
+    let days = secs / 86400;
+    let micros = d.subsec_micros();
+
+    let mut s = String::new();
+    if days > 0 {
+        s += &format!("{days} {}", if days == 1 { "day" } else { "days" });
+    }
+
+    if days == 0 || !secs.is_multiple_of(86400) || micros > 0 {
+        if !s.is_empty() {
+            s.push(' ');
+        }
+        s += &format!(
+            "{:02}:{:02}:{:02}",
+            secs / 3600 % 24,
+            secs / 60 % 60,
+            secs % 60
+        );
+        if micros > 0 {
+            let fraction = format!("{micros:06}");
+            s += &format!(".{}", fraction.trim_end_matches('0'));
+        }
+    }
+
+    s
+}
@@ -0,0 +1,117 @@
+use std::cmp::Reverse;
+
+use crate::{Parser, lexer::TokenKind};
+
+/// A table of fuzzy quantifiers, like "a few" or "a couple", and the numbers
+/// they stand for.
+///
+/// When set in [`ParserOptions::quantifiers`](crate::ParserOptions::quantifiers),
+/// a quantifier can be used in place of a number, optionally followed by "of"
+/// ("a couple of hours").
+///
+/// Each quantifier stands for a single number, so the result is only an
+/// estimate, which [`Parser::parse_with_info`] reports as
+/// [`ParseInfo::approximate`](crate::ParseInfo::approximate). Ranges of
+/// durations ("a few to several minutes") are not supported.
+///
+/// ## Example
+/// ```rust
+/// use durstr::{Parser, ParserOptions, Quantifiers};
+/// use std::time::Duration;
+///
+/// let mut quantifiers = Quantifiers::default();
+/// quantifiers.add_quantifier("several", 5);
+///
+/// let parser = Parser::new(ParserOptions {
+///     quantifiers: Some(quantifiers),
+///     ..Default::default()
+/// });
+///
+/// let d = parser.parse("a couple of hours, several minutes");
+/// assert_eq!(d, Ok(Duration::from_secs(2 * 3600 + 5 * 60)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Quantifiers {
+    values: Vec<(Vec<&'static str>, u32)>,
+}
+
+impl Quantifiers {
+    /// Returns a Quantifiers with no quantifiers (empty table).
+    pub fn new() -> Self {
+        Quantifiers { values: vec![] }
+    }
+
+    /// Insert/update a quantifier and its value.
+    ///
+    /// A quantifier may have multiple words separated by whitespace, such as
+    /// "a handful".
+    pub fn add_quantifier(&mut self, phrase: &'static str, value: u32) {
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        self.values.retain(|(w, _)| *w != words);
+        self.values.push((words, value));
+        // longest phrases first, so "a few" is preferred over "a"
+        self.values.sort_by_key(|(w, _)| Reverse(w.len()));
+    }
+}
+
+impl Default for Quantifiers {
+    /// Provides the default set of quantifiers.
+    ///
+    /// Default Quantifiers
+    /// - `a`, `an`: 1
+    /// - `a couple`: 2
+    /// - `a few`: 3
+    fn default() -> Self {
+        let mut quantifiers = Quantifiers::new();
+
+        quantifiers.add_quantifier("a", 1);
+        quantifiers.add_quantifier("an", 1);
+        quantifiers.add_quantifier("a couple", 2);
+        quantifiers.add_quantifier("a few", 3);
+
+        quantifiers
+    }
+}
+
+impl Parser {
+    /// Replaces the quantifiers in `tokens`, and any "of" following them, with
+    /// the numbers they stand for, also returning whether there were any.
+    pub(crate) fn replace_quantifiers<'a>(
+        &self,
+        quantifiers: &Quantifiers,
+        tokens: Vec<TokenKind<'a>>,
+    ) -> (Vec<TokenKind<'a>>, bool) {
+        let mut replaced = Vec::with_capacity(tokens.len());
+        let mut quantified = false;
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let matched = quantifiers.values.iter().find(|(words, _)| {
+                words.len() <= tokens.len() - i
+                    && words.iter().zip(&tokens[i..]).all(|(w, t)| match t {
+                        TokenKind::Unit(u) => self.normalize_unit(u) == *w,
+                        TokenKind::Number(_) => false,
+                    })
+            });
+
+            match matched {
+                Some((words, value)) => {
+                    i += words.len();
+                    if let Some(TokenKind::Unit(u)) = tokens.get(i)
+                        && self.normalize_unit(u) == "of"
+                    {
+                        i += 1;
+                    }
+                    replaced.push(TokenKind::Number(u64::from(*value).into()));
+                    quantified = true;
+                }
+                None => {
+                    replaced.push(tokens[i]);
+                    i += 1;
+                }
+            }
+        }
+
+        (replaced, quantified)
+    }
+}
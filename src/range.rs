@@ -0,0 +1,85 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Error, clock};
+
+const DAY: Duration = Duration::from_secs(24 * 3600);
+
+/// Parses a range of times of day, `h:mm[:ss]-h:mm[:ss]`, into the length of
+/// time between them, as used for shifts and opening hours.
+///
+/// A range that ends at or before its start crosses midnight, so
+/// "22:00-6:00" is 8 hours. An end of "24:00" is accepted for the end of the
+/// day. Spaces around the dash are allowed, as is an en dash ("9:00–17:30").
+///
+/// ## Examples
+/// ```
+/// use durstr::parse_clock_range;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_clock_range("9:00-17:30"), Ok(Duration::from_secs(30600)));
+/// assert_eq!(parse_clock_range("22:00 - 06:00"), Ok(Duration::from_secs(28800)));
+/// ```
+pub fn parse_clock_range(input: &str) -> Result<Duration, Error> {
+    let (start, end) = input
+        .split_once(['-', '–'])
+        .ok_or(Error::ExpectedClockRange)?;
+
+    let start = clock::parse_clock(start.trim(), 23, 0)?;
+    let end = match end.trim() {
+        "24:00" | "24:00:00" => DAY,
+        end => clock::parse_clock(end, 23, 0)?,
+    };
+
+    match end > start {
+        true => Ok(end - start),
+        false => Ok(end + DAY - start),
+    }
+}
+
+/// Parses "until" or "till" followed by a time of day, `h:mm[:ss]`,
+/// "midnight" or "noon", into the time left from `now` until then.
+///
+/// A time that has already passed today, or is exactly `now`, is taken to
+/// be tomorrow, so the result is always more than zero and at most a day.
+/// Times of day are in UTC; to use another time zone, add its UTC offset to
+/// `now`.
+///
+/// ## Examples
+/// ```
+/// use durstr::parse_until;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// // 16:00 UTC
+/// let now = UNIX_EPOCH + Duration::from_secs(16 * 3600);
+///
+/// assert_eq!(parse_until("until 17:30", now), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parse_until("till midnight", now), Ok(Duration::from_secs(8 * 3600)));
+/// assert_eq!(parse_until("until 9:00", now), Ok(Duration::from_secs(17 * 3600)));
+/// ```
+pub fn parse_until(input: &str, now: SystemTime) -> Result<Duration, Error> {
+    let input = input.trim();
+    let (word, target) = input
+        .split_once(char::is_whitespace)
+        .ok_or(Error::ExpectedUntil)?;
+    if !["until", "till", "til"].contains(&word.to_lowercase().as_str()) {
+        return Err(Error::ExpectedUntil);
+    }
+
+    let target = match target.trim().to_lowercase().as_str() {
+        "midnight" | "24:00" | "24:00:00" => Duration::ZERO,
+        "noon" => DAY / 2,
+        target => clock::parse_clock(target, 23, 0)?,
+    };
+
+    let day = DAY.as_nanos();
+    let time_of_day = match now.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_nanos() % day,
+        Err(e) => (day - e.duration().as_nanos() % day) % day,
+    };
+    let left = (target.as_nanos() + day - time_of_day - 1) % day + 1;
+
+    Ok(Duration::new(
+        (left / 1_000_000_000) as u64,
+        (left % 1_000_000_000) as u32,
+    ))
+}
@@ -0,0 +1,18 @@
+use crate::Parser;
+
+impl Parser {
+    /// Returns how many of `unit` make up a second, if it is a unit whose
+    /// length is set by a rate in the options, like "ticks" with
+    /// [`ParserOptions::tick_rate`](crate::ParserOptions::tick_rate).
+    ///
+    /// `unit` must already be normalized.
+    pub(crate) fn unit_rate(&self, unit: &str) -> Option<u32> {
+        let rate = match unit {
+            "tick" | "ticks" => self.options.tick_rate,
+            "sample" | "samples" => self.options.sample_rate,
+            _ => None,
+        };
+
+        rate.filter(|&r| r > 0)
+    }
+}
@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use crate::{
+    Error, Parser,
+    lexer::{Lexer, Token, TokenKind},
+};
+
+/// A periodic schedule, such as "every 5m for 2h".
+///
+/// Returned by [`Parser::parse_schedule`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    period: Duration,
+    span: Duration,
+}
+
+impl Schedule {
+    /// The time between runs. This is never zero.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// The total time the schedule runs for.
+    pub fn span(&self) -> Duration {
+        self.span
+    }
+
+    /// Returns an iterator over the offsets of each run from the start of the
+    /// schedule, starting at zero and stopping before the end of the span.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use durstr::parse_schedule;
+    /// use std::time::Duration;
+    ///
+    /// let schedule = parse_schedule("every 20 min for 1h").unwrap();
+    /// let offsets: Vec<_> = schedule.offsets().collect();
+    /// assert_eq!(offsets, [0, 1200, 2400].map(Duration::from_secs));
+    /// ```
+    pub fn offsets(&self) -> Offsets {
+        Offsets {
+            next: Some(Duration::ZERO),
+            period: self.period,
+            span: self.span,
+        }
+    }
+}
+
+impl IntoIterator for Schedule {
+    type Item = Duration;
+    type IntoIter = Offsets;
+
+    fn into_iter(self) -> Offsets {
+        self.offsets()
+    }
+}
+
+/// An iterator over the offsets of a [`Schedule`].
+#[derive(Clone, Debug)]
+pub struct Offsets {
+    next: Option<Duration>,
+    period: Duration,
+    span: Duration,
+}
+
+impl Iterator for Offsets {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let offset = self.next.filter(|&o| o < self.span)?;
+        self.next = offset.checked_add(self.period);
+        Some(offset)
+    }
+}
+
+/// An amount of time spent every period over a span, such as "30 minutes a
+/// day for 2 weeks".
+///
+/// Returned by [`Parser::parse_cumulative`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cumulative {
+    amount: Duration,
+    period: Duration,
+    span: Duration,
+    total: Duration,
+}
+
+impl Cumulative {
+    /// The time spent each period, like the "30 minutes" in "30 minutes a
+    /// day for 2 weeks".
+    pub fn amount(&self) -> Duration {
+        self.amount
+    }
+
+    /// The length of a period, like the day in "30 minutes a day". This is
+    /// never zero.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// The total time the amount is spent over, like the "2 weeks" in "30
+    /// minutes a day for 2 weeks".
+    pub fn span(&self) -> Duration {
+        self.span
+    }
+
+    /// The total time spent over the whole span. A span that is not a whole
+    /// number of periods counts the partial period proportionally.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+}
+
+impl Parser {
+    /// Parses a schedule of the form "every `period` for `span`", where the
+    /// leading "every" is optional.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::Parser;
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    /// let schedule = parser.parse_schedule("every 5m for 2h").unwrap();
+    /// assert_eq!(schedule.period(), Duration::from_secs(300));
+    /// assert_eq!(schedule.span(), Duration::from_secs(7200));
+    /// assert_eq!(schedule.offsets().count(), 24);
+    /// ```
+    pub fn parse_schedule(&self, input: &str) -> Result<Schedule, Error> {
+        let mut tokens = self.scan(input)?;
+
+        let split = tokens
+            .iter()
+            .position(|t| matches!(t, TokenKind::Unit(u) if self.normalize_unit(u) == "for"))
+            .ok_or(Error::ExpectedScheduleSpan)?;
+        let span_tokens = tokens.split_off(split + 1);
+        tokens.pop();
+
+        if span_tokens.is_empty() {
+            return Err(Error::ExpectedScheduleSpan);
+        }
+
+        let period = self.parse_tokens(tokens)?;
+        let span = self.parse_tokens(span_tokens)?;
+        if period.is_zero() {
+            return Err(Error::ZeroSchedulePeriod);
+        }
+
+        Ok(Schedule { period, span })
+    }
+
+    /// Parses a cumulative phrase of the form "`amount` per `period` for
+    /// `span`", where "per" may also be "a", "an", "every" or "each", and a
+    /// period without a number is one of its unit ("a day").
+    ///
+    /// The units of the parser are used throughout, so units like days and
+    /// weeks must be added to [`ParserOptions::units`](crate::ParserOptions::units)
+    /// to parse phrases like "30 minutes a day for 2 weeks".
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::{Parser, ParserOptions, ParserUnits};
+    /// use std::time::Duration;
+    ///
+    /// let mut units = ParserUnits::default();
+    /// units.add_unit("day", Duration::from_secs(86400));
+    /// units.add_unit("weeks", Duration::from_secs(86400 * 7));
+    ///
+    /// let parser = Parser::new(ParserOptions { units, ..Default::default() });
+    /// let goal = parser.parse_cumulative("30 minutes a day for 2 weeks").unwrap();
+    /// assert_eq!(goal.amount(), Duration::from_secs(1800));
+    /// assert_eq!(goal.total(), Duration::from_secs(1800 * 14));
+    /// ```
+    pub fn parse_cumulative(&self, input: &str) -> Result<Cumulative, Error> {
+        let tokens = Lexer::new(input).collect::<Result<Vec<_>, _>>()?;
+        let is_word = |t: &Token, words: &[&str]| matches!(t.kind, TokenKind::Unit(u) if words.contains(&self.normalize_unit(u).as_ref()));
+
+        let for_index = tokens
+            .iter()
+            .position(|t| is_word(t, &["for"]))
+            .ok_or(Error::ExpectedScheduleSpan)?;
+        let per_index = tokens[..for_index]
+            .iter()
+            .rposition(|t| is_word(t, &["per", "a", "an", "every", "each"]))
+            .ok_or(Error::ExpectedPeriod)?;
+        let (per, for_) = (&tokens[per_index], &tokens[for_index]);
+
+        let mut period_tokens = self.scan(&input[per.span.end..for_.span.start])?;
+        if let [TokenKind::Unit(_)] = period_tokens[..] {
+            period_tokens.insert(0, TokenKind::Number(1.into()));
+        }
+        let span_input = &input[for_.span.end..];
+        if span_input.trim().is_empty() {
+            return Err(Error::ExpectedScheduleSpan);
+        }
+
+        let amount = self.parse(&input[..per.span.start])?;
+        let period = self.parse_tokens(period_tokens)?;
+        let span = self.parse(span_input)?;
+        if period.is_zero() {
+            return Err(Error::ZeroSchedulePeriod);
+        }
+
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+        let nanos = amount
+            .as_nanos()
+            .checked_mul(span.as_nanos())
+            .ok_or(Error::Overflow)?
+            / period.as_nanos();
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).map_err(|_| Error::Overflow)?;
+        let total = Duration::new(secs, (nanos % NANOS_PER_SEC) as u32);
+
+        Ok(Cumulative {
+            amount,
+            period,
+            span,
+            total,
+        })
+    }
+}
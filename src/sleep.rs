@@ -0,0 +1,112 @@
+/*!
+Compatibility with the arguments of GNU coreutils `sleep`, like "1.5m 30".
+
+## Example
+
+```rust
+use durstr::sleep;
+use std::time::Duration;
+
+let dur = sleep::parse("1.5m");
+assert_eq!(dur, Ok(Duration::from_secs(90)));
+
+let dur = sleep::parse("1m 30");
+assert_eq!(dur, Ok(Duration::from_secs(90)));
+
+let dur = sleep::parse(".5");
+assert_eq!(dur, Ok(Duration::from_millis(500)));
+```
+*/
+
+use std::{iter, time::Duration};
+
+use crate::{Error, ParserUnits, Unit, lexer::Quantity};
+
+impl ParserUnits {
+    /// Returns the suffixes accepted by GNU `sleep`.
+    ///
+    /// Units
+    /// - `s`
+    /// - `m`
+    /// - `h`
+    /// - `d`
+    pub fn sleep() -> Self {
+        let mut units = ParserUnits::new();
+
+        units.add_standard_unit("s", Unit::Second);
+        units.add_standard_unit("m", Unit::Minute);
+        units.add_standard_unit("h", Unit::Hour);
+        units.add_unit("d", Duration::from_secs(86400));
+
+        units
+    }
+}
+
+/// Parses the arguments of GNU `sleep` into a `Duration`, given as a single
+/// string.
+///
+/// Each whitespace-separated argument is a number, optionally followed
+/// directly by one of the suffixes of [`ParserUnits::sleep`], and the
+/// arguments are added up. A number without a suffix is in seconds, and may
+/// have a fractional part (".5", "1.", "2.25h"). At least one argument is
+/// required.
+///
+/// Unlike `sleep`, exponents ("1e3"), hexadecimal numbers and "infinity" are
+/// not accepted.
+pub fn parse(input: &str) -> Result<Duration, Error> {
+    let units = ParserUnits::sleep();
+    let mut total = None;
+
+    for arg in input.split_ascii_whitespace() {
+        let (num, unit_duration) = match arg.char_indices().last() {
+            Some((i, c)) if c.is_ascii_alphabetic() => {
+                let unit = &arg[i..];
+                let d = units.get_duration(unit).copied();
+                (
+                    &arg[..i],
+                    d.ok_or_else(|| Error::UnexpectedUnit(unit.to_owned()))?,
+                )
+            }
+            _ => (arg, Duration::from_secs(1)),
+        };
+
+        total = parse_number(num)?
+            .checked_mul(unit_duration)
+            .and_then(|d| total.unwrap_or(Duration::ZERO).checked_add(d))
+            .map(Some)
+            .ok_or(Error::Overflow)?;
+    }
+
+    total.ok_or(Error::ExpectedNumber)
+}
+
+/// Parses a decimal number of ASCII digits, where either side of the
+/// decimal point may be empty, but not both.
+fn parse_number(s: &str) -> Result<Quantity, Error> {
+    let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+    if whole.is_empty() && frac.is_empty() {
+        return Err(Error::ExpectedNumber);
+    }
+    if let Some(c) = whole
+        .chars()
+        .chain(frac.chars())
+        .find(|c| !c.is_ascii_digit())
+    {
+        return Err(Error::UnexpectedChar(c));
+    }
+
+    let whole = match whole {
+        "" => 0,
+        whole => whole.parse().map_err(|_| Error::Overflow)?,
+    };
+    let frac = frac
+        .bytes()
+        .chain(iter::repeat(b'0'))
+        .take(9)
+        .fold(0, |n, b| n * 10 + u32::from(b - b'0'));
+
+    Ok(Quantity {
+        whole,
+        frac: Some(frac),
+    })
+}
@@ -0,0 +1,125 @@
+use std::env;
+
+use crate::{Error, ParserOptions, ParserUnits};
+
+/// The environment variable read by [`ParserOptions::with_env_overrides`].
+const ENV_VAR: &str = "DURSTR_OPTIONS";
+
+impl ParserOptions {
+    /// Creates options from a spec string, such as
+    /// `"ignore_case,units=s|m|h,default_unit=s"`.
+    ///
+    /// The spec is a comma-separated list of items, applied in order on top
+    /// of the default options:
+    /// - `ignore_case`, `allow_fractions`, `correct_typos`, `reject_zero`:
+    ///   enable the option of the same name
+    /// - `units=a|b|...`: only allow the listed default units
+    /// - `default_unit=u`: sets [`ParserOptions::default_unit`]
+    /// - `min_unit=u`: sets [`ParserOptions::min_unit`] to the value of `u`
+    /// - `max_components=n`: sets [`ParserOptions::max_components`]
+    /// - `tick_rate=n`: sets [`ParserOptions::tick_rate`]
+    /// - `sample_rate=n`: sets [`ParserOptions::sample_rate`]
+    ///
+    /// Units in `default_unit` and `min_unit` must be in the units at that
+    /// point of the spec. Unknown or malformed items are an
+    /// [`Error::InvalidSpec`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::{Error, Parser, ParserOptions};
+    /// use std::time::Duration;
+    ///
+    /// let options = ParserOptions::from_spec("ignore_case,units=s|m|h,default_unit=s").unwrap();
+    /// let parser = Parser::new(options);
+    ///
+    /// assert_eq!(parser.parse("1 M 30"), Err(Error::ExpectedUnit));
+    /// assert_eq!(parser.parse("90"), Ok(Duration::from_secs(90)));
+    /// assert_eq!(parser.parse("1M 30S"), Ok(Duration::from_secs(90)));
+    /// assert_eq!(parser.parse("1 min"), Err(Error::UnexpectedUnit("min".to_owned())));
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<Self, Error> {
+        let mut options = ParserOptions::default();
+        options.apply_spec(spec)?;
+        Ok(options)
+    }
+
+    /// Applies the items of a spec string on top of these options. See
+    /// [`ParserOptions::from_spec`] for the format.
+    pub fn apply_spec(&mut self, spec: &str) -> Result<(), Error> {
+        let invalid = |item: &str| Error::InvalidSpec(item.to_owned());
+
+        for item in spec.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+            let (key, value) = match item.split_once('=') {
+                Some((k, v)) => (k.trim(), Some(v.trim())),
+                None => (item, None),
+            };
+
+            match (key, value) {
+                ("ignore_case", None) => self.ignore_case = true,
+                ("allow_fractions", None) => self.allow_fractions = true,
+                ("correct_typos", None) => self.correct_typos = true,
+                ("reject_zero", None) => self.reject_zero = true,
+                ("units", Some(list)) => {
+                    let defaults = ParserUnits::default();
+                    let mut units = ParserUnits::new();
+                    for name in list.split('|').map(str::trim) {
+                        let (&k, &v) = defaults
+                            .values
+                            .get_key_value(name)
+                            .ok_or_else(|| invalid(item))?;
+                        match defaults.standard.get(k) {
+                            Some(&unit) => units.add_standard_unit(k, unit),
+                            None => units.add_unit(k, v),
+                        }
+                    }
+                    self.units = units;
+                }
+                ("default_unit", Some(name)) => {
+                    let (&k, _) = self
+                        .units
+                        .values
+                        .get_key_value(name)
+                        .ok_or_else(|| invalid(item))?;
+                    self.default_unit = Some(k);
+                }
+                ("min_unit", Some(name)) => {
+                    let d = self.units.get_duration(name).ok_or_else(|| invalid(item))?;
+                    self.min_unit = Some(*d);
+                }
+                ("max_components", Some(n)) => {
+                    self.max_components = Some(n.parse().map_err(|_| invalid(item))?);
+                }
+                ("tick_rate", Some(n)) => {
+                    self.tick_rate = Some(n.parse().map_err(|_| invalid(item))?);
+                }
+                ("sample_rate", Some(n)) => {
+                    self.sample_rate = Some(n.parse().map_err(|_| invalid(item))?);
+                }
+                _ => return Err(invalid(item)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies the spec in the `DURSTR_OPTIONS` environment variable, if it
+    /// is set, so parsing can be tweaked without recompiling.
+    ///
+    /// ## Examples
+    /// ```no_run
+    /// use durstr::{Parser, ParserOptions};
+    ///
+    /// // DURSTR_OPTIONS="ignore_case,max_components=4"
+    /// let options = ParserOptions {
+    ///     allow_fractions: true,
+    ///     ..Default::default()
+    /// };
+    /// let parser = Parser::new(options.with_env_overrides().unwrap());
+    /// ```
+    pub fn with_env_overrides(mut self) -> Result<Self, Error> {
+        if let Ok(spec) = env::var(ENV_VAR) {
+            self.apply_spec(&spec)?;
+        }
+        Ok(self)
+    }
+}
@@ -0,0 +1,70 @@
+use std::cmp;
+
+use crate::Parser;
+
+/// Units shorter than this are never corrected, as nearly any short word is
+/// one typo away from a symbol like "s" or "ms".
+const MIN_TYPO_LEN: usize = 3;
+
+impl Parser {
+    /// Returns the known unit that `unit` is a typo of, if typo correction is
+    /// enabled and there is exactly one such unit (counting aliases of the
+    /// same value as one).
+    pub(crate) fn correct_typo(&self, unit: &str) -> Option<&'static str> {
+        let len = unit.chars().count();
+        if !self.options.correct_typos || len < MIN_TYPO_LEN {
+            return None;
+        }
+
+        let mut candidates = self
+            .options
+            .units
+            .values
+            .iter()
+            .filter(|(k, _)| is_one_edit_away(unit, k));
+
+        // prefer aliases of the same length, so "minutse" is "minutes" rather
+        // than "minute"
+        let rank = |k: &'static str| (k.chars().count().abs_diff(len), k);
+
+        let (&first, &value) = candidates.next()?;
+        candidates.try_fold(first, |best, (&k, &v)| match v == value {
+            true => Some(cmp::min_by_key(best, k, |&k| rank(k))),
+            false => None,
+        })
+    }
+}
+
+/// Returns whether `a` can be turned into `b` with exactly one insertion,
+/// deletion, substitution or swap of adjacent characters.
+fn is_one_edit_away(a: &str, b: &str) -> bool {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let (short, long) = if a.len() <= b.len() {
+        (&a, &b)
+    } else {
+        (&b, &a)
+    };
+
+    if long.len() - short.len() > 1 {
+        return false;
+    }
+
+    // skip the common prefix and suffix, leaving only the edited part
+    let prefix = short.iter().zip(long).take_while(|(x, y)| x == y).count();
+    let suffix = short[prefix..]
+        .iter()
+        .rev()
+        .zip(long[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (short, long) = (
+        &short[prefix..short.len() - suffix],
+        &long[prefix..long.len() - suffix],
+    );
+
+    match (short, long) {
+        ([], [_]) | ([_], [_]) => true,
+        ([x1, x2], [y1, y2]) => x1 == y2 && x2 == y1,
+        _ => false,
+    }
+}
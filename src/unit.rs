@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+/// A unit of time, as recognized by the parser and used when formatting
+/// durations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+}
+
+impl Unit {
+    /// All units, from largest to smallest.
+    pub const ALL: [Unit; 4] = [Unit::Hour, Unit::Minute, Unit::Second, Unit::Millisecond];
+
+    /// Returns the length of one of this unit.
+    pub fn duration(self) -> Duration {
+        match self {
+            Unit::Millisecond => Duration::from_millis(1),
+            Unit::Second => Duration::from_secs(1),
+            Unit::Minute => Duration::from_secs(60),
+            Unit::Hour => Duration::from_secs(3600),
+        }
+    }
+}
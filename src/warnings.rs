@@ -0,0 +1,90 @@
+use std::{fmt, time::Duration};
+
+use crate::{Error, Parser, add_durations, lexer::TokenKind};
+
+/// Something questionable about an input that still parsed successfully.
+///
+/// Returned by [`Parser::parse_with_warnings`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Warning {
+    /// An approximate unit was used, so the duration is an estimate. See
+    /// [`ParserUnits::add_approximate_unit`](crate::ParserUnits::add_approximate_unit).
+    ApproximateUnit(String),
+    /// A unit was used more than once, possibly under different aliases
+    /// ("1h 30m 1hr").
+    RepeatedUnit(String),
+    /// A unit was not known, but was taken to be a typo of a known unit. See
+    /// [`ParserOptions::correct_typos`](crate::ParserOptions::correct_typos).
+    CorrectedUnit { found: String, unit: String },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::ApproximateUnit(u) => write!(f, "approximate unit: {u}"),
+            Warning::RepeatedUnit(u) => write!(f, "repeated unit: {u}"),
+            Warning::CorrectedUnit { found, unit } => {
+                write!(f, "unexpected unit {found}, assuming {unit}")
+            }
+        }
+    }
+}
+
+impl Parser {
+    /// Parses a string into a `Duration`, like [`Parser::parse`], and returns
+    /// it along with [`Warning`]s about anything questionable in the input.
+    ///
+    /// Warnings are in the order they appear in the input. Units are
+    /// compared by their value, so two aliases of the same unit count as a
+    /// repeat.
+    ///
+    /// ## Examples
+    /// ```
+    /// use durstr::{Parser, Warning};
+    /// use std::time::Duration;
+    ///
+    /// let parser = Parser::default();
+    ///
+    /// let (d, warnings) = parser.parse_with_warnings("1h 30m 1hr").unwrap();
+    /// assert_eq!(d, Duration::from_secs(9000));
+    /// assert_eq!(warnings, [Warning::RepeatedUnit("hr".to_owned())]);
+    /// ```
+    pub fn parse_with_warnings(&self, input: &str) -> Result<(Duration, Vec<Warning>), Error> {
+        let tokens = self.scan(input)?;
+        self.parse_tokens_with_warnings(tokens)
+    }
+
+    pub(crate) fn parse_tokens_with_warnings(
+        &self,
+        tokens: Vec<TokenKind>,
+    ) -> Result<(Duration, Vec<Warning>), Error> {
+        let mut dur = Duration::ZERO;
+        let mut warnings = vec![];
+        let mut seen = vec![];
+
+        self.for_each_clause(tokens, |num, unit| {
+            dur = add_durations(dur, self.clause_duration(num, unit)?)?;
+
+            let (unit_duration, corrected) = self.lookup_unit(unit)?;
+            let mut unit = self.normalize_unit(unit);
+            if let Some(corrected) = corrected {
+                warnings.push(Warning::CorrectedUnit {
+                    found: unit.into_owned(),
+                    unit: corrected.to_owned(),
+                });
+                unit = corrected.into();
+            }
+            if self.options.units.is_approximate(&unit) {
+                warnings.push(Warning::ApproximateUnit(unit.to_string()));
+            }
+            if seen.contains(&unit_duration) {
+                warnings.push(Warning::RepeatedUnit(unit.into_owned()));
+            } else {
+                seen.push(unit_duration);
+            }
+            Ok(())
+        })?;
+
+        Ok((dur, warnings))
+    }
+}
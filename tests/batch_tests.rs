@@ -0,0 +1,37 @@
+#![cfg(feature = "parallel")]
+
+use std::time::Duration;
+
+use durstr::{Error, Parser, ParserOptions, parse, parse_batch};
+
+#[test]
+fn test_parsing_batches() {
+    let inputs: Vec<String> = (0..1000)
+        .map(|i| match i % 3 {
+            0 => format!("{i} seconds"),
+            1 => format!("{i}m 1s"),
+            _ => format!("{i} x"),
+        })
+        .collect();
+    let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+
+    let results = parse_batch(&inputs);
+    let expected: Vec<_> = inputs.iter().map(|input| parse(input)).collect();
+    assert_eq!(results, expected);
+
+    assert_eq!(parse_batch(&[]), vec![]);
+
+    let parser = Parser::new(ParserOptions {
+        ignore_case: true,
+        ..Default::default()
+    });
+    let results = parser.parse_batch(&["1 MIN", "2 hrs", "3"]);
+    assert_eq!(
+        results,
+        [
+            Ok(Duration::from_secs(60)),
+            Ok(Duration::from_secs(7200)),
+            Err(Error::ExpectedUnit)
+        ]
+    );
+}
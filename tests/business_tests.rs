@@ -0,0 +1,146 @@
+#![cfg(feature = "business")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use durstr::{BusinessSchedule, Error, Parser, ParserOptions, parse};
+
+const DAY: Duration = Duration::from_secs(86400);
+
+fn date(days_since_epoch: u32) -> SystemTime {
+    UNIX_EPOCH + DAY * days_since_epoch
+}
+
+#[test]
+fn test_parsing_business_units() {
+    let parser = Parser::new(ParserOptions {
+        business: Some(BusinessSchedule {
+            workday: Duration::from_secs(3600) * 7,
+            days_per_week: 4,
+            is_holiday: None,
+        }),
+        ..Default::default()
+    });
+
+    let d = parser.parse("2 business days");
+    assert_eq!(d, Ok(Duration::from_secs(3600) * 14));
+
+    let d = parser.parse("1 workweek 1 workday 30 min");
+    assert_eq!(
+        d,
+        Ok(Duration::from_secs(3600) * 35 + Duration::from_secs(1800))
+    );
+
+    let d = parser.parse("1 business hour");
+    assert_eq!(d, Err(Error::UnexpectedUnit("business hour".to_owned())));
+
+    let d = parse("2 business days");
+    assert_eq!(d, Err(Error::UnexpectedUnit("business".to_owned())));
+}
+
+#[test]
+fn test_parsing_business_days_from_anchor() {
+    let parser = Parser::new(ParserOptions {
+        business: Some(BusinessSchedule::default()),
+        ..Default::default()
+    });
+
+    // Wednesday 2024-01-31 + 2 business days = Friday 2024-02-02
+    let d = parser.parse_from("2 business days", date(19753));
+    assert_eq!(d, Ok(DAY * 2));
+
+    // Friday 2024-02-02 + 1 workday = Monday 2024-02-05
+    let d = parser.parse_from("1 workday", date(19755));
+    assert_eq!(d, Ok(DAY * 3));
+
+    let d = parser.parse_from("1 workweek", date(19755));
+    assert_eq!(d, Ok(DAY * 7));
+
+    let parser = Parser::new(ParserOptions {
+        business: Some(BusinessSchedule {
+            is_holiday: Some(|y, m, d| (y, m, d) == (2024, 2, 5)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let d = parser.parse_from("1 business day", date(19755));
+    assert_eq!(d, Ok(DAY * 4));
+}
+
+#[test]
+fn test_business_days_limits() {
+    let parser = Parser::new(ParserOptions {
+        business: Some(BusinessSchedule::default()),
+        ..Default::default()
+    });
+
+    // whole weeks are skipped without walking the calendar
+    let d = parser.parse_from("100000000000 business days", date(19753));
+    assert_eq!(d, Ok(Duration::from_secs(86400 * 140_000_000_000)));
+
+    // Wednesday 2024-01-31 + 7 business days = Friday 2024-02-09
+    let d = parser.parse_from("7 business days", date(19753));
+    assert_eq!(d, Ok(DAY * 9));
+
+    let d = parser.parse_from("2 business, days", date(19753));
+    assert_eq!(d, Err(Error::UnexpectedUnit("business".to_owned())));
+
+    let parser = Parser::new(ParserOptions {
+        business: Some(BusinessSchedule {
+            is_holiday: Some(|_, _, _| true),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let d = parser.parse_from("1 business day", date(19753));
+    assert_eq!(d, Err(Error::NoWorkingDay));
+
+    let parser = Parser::new(ParserOptions {
+        business: Some(BusinessSchedule {
+            is_holiday: Some(|_, m, d| (m, d) == (12, 25)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let d = parser.parse_from("100000000000 business days", date(19753));
+    assert_eq!(d, Err(Error::Overflow));
+}
+
+#[test]
+fn test_business_days_from_weekend() {
+    let parser = Parser::new(ParserOptions {
+        business: Some(BusinessSchedule::default()),
+        ..Default::default()
+    });
+    let with_holidays = Parser::new(ParserOptions {
+        business: Some(BusinessSchedule {
+            is_holiday: Some(|_, _, _| false),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    // Saturday 2024-01-06 + 5 business days = Friday 2024-01-12
+    let d = parser.parse_from("5 business days", date(19728));
+    assert_eq!(d, Ok(DAY * 6));
+    let d = parser.parse_from("1 workweek", date(19728));
+    assert_eq!(d, Ok(DAY * 6));
+
+    // Sunday 2024-01-07 + 1 business day = Monday 2024-01-08
+    let d = parser.parse_from("1 business day", date(19729));
+    assert_eq!(d, Ok(DAY));
+
+    for start in [19728, 19729] {
+        for input in [
+            "1 business day",
+            "5 business days",
+            "1 workweek",
+            "12 workdays",
+        ] {
+            let d = parser.parse_from(input, date(start));
+            assert_eq!(d, with_holidays.parse_from(input, date(start)), "{input}");
+        }
+    }
+}
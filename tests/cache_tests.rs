@@ -0,0 +1,56 @@
+#![cfg(feature = "cache")]
+
+use std::time::Duration;
+
+use durstr::{CachedParser, Error, Parser};
+
+#[test]
+fn test_cached_parsing() {
+    let parser = CachedParser::new(Parser::default(), 2);
+    assert!(parser.is_empty());
+
+    assert_eq!(parser.parse("1s"), Ok(Duration::from_secs(1)));
+    assert_eq!(
+        parser.parse("2 x"),
+        Err(Error::UnexpectedUnit("x".to_owned()))
+    );
+    assert_eq!(parser.len(), 2);
+
+    // "1s" was used more recently than "2 x", so "2 x" is evicted
+    assert_eq!(parser.parse("1s"), Ok(Duration::from_secs(1)));
+    assert_eq!(parser.parse("3m"), Ok(Duration::from_secs(180)));
+    assert_eq!(parser.len(), 2);
+    assert_eq!(
+        parser.parse("2 x"),
+        Err(Error::UnexpectedUnit("x".to_owned()))
+    );
+    assert_eq!(parser.len(), 2);
+
+    parser.clear();
+    assert!(parser.is_empty());
+
+    let parser = CachedParser::new(Parser::default(), 0);
+    assert_eq!(parser.parse("1s"), Ok(Duration::from_secs(1)));
+    assert!(parser.is_empty());
+}
+
+#[test]
+fn test_cached_parsing_across_threads() {
+    let parser = CachedParser::new(Parser::default(), 4);
+    let inputs = ["1s", "2m", "3h", "4ms", "5s", "6m"];
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..100 {
+                    for (i, input) in inputs.iter().enumerate() {
+                        let expected = Parser::default().parse(input);
+                        assert_eq!(parser.parse(input), expected, "input {i}");
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(parser.len(), 4);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "calendar")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use durstr::{Error, Parser, ParserOptions};
+
+const DAY: Duration = Duration::from_secs(86400);
+
+fn date(days_since_epoch: u32) -> SystemTime {
+    UNIX_EPOCH + DAY * days_since_epoch
+}
+
+#[test]
+fn test_parsing_from_anchor() {
+    let parser = Parser::default();
+
+    // 2024-01-31 + 1 month = 2024-02-29 (leap year)
+    let d = parser.parse_from("1 month", date(19753));
+    assert_eq!(d, Ok(DAY * 29));
+
+    // 2023-01-31 + 1 month = 2023-02-28
+    let d = parser.parse_from("1 month", date(19388));
+    assert_eq!(d, Ok(DAY * 28));
+
+    // 2024-02-29 + 1 year = 2025-02-28
+    let d = parser.parse_from("1 year", date(19782));
+    assert_eq!(d, Ok(DAY * 365));
+
+    // 2024-03-01 + 1 year = 2025-03-01
+    let d = parser.parse_from("1 yr 2 hours", date(19783) + Duration::from_secs(600));
+    assert_eq!(d, Ok(DAY * 365 + Duration::from_secs(7200)));
+
+    let d = parser.parse_from("13 months", date(19753));
+    let expected = parser.parse_from("1 year 1 month", date(19753));
+    assert_eq!(d, expected);
+
+    // before the unix epoch: 1969-12-31 + 2 months = 1970-02-28
+    let d = parser.parse_from("2 months", UNIX_EPOCH - DAY);
+    assert_eq!(d, Ok(DAY * 59));
+
+    let d = parser.parse_from("1.5 months", date(0));
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+
+    let d = parser.parse_from("1 fortnight", date(0));
+    assert_eq!(d, Err(Error::UnexpectedUnit("fortnight".to_owned())));
+
+    let parser = Parser::new(ParserOptions {
+        ignore_case: true,
+        ..Default::default()
+    });
+    let d = parser.parse_from("1 Month", date(19753));
+    assert_eq!(d, Ok(DAY * 29));
+}
+
+#[test]
+fn test_calendar_overflow() {
+    let parser = Parser::default();
+
+    let d = parser.parse_from("99999999999999999 years", UNIX_EPOCH);
+    assert_eq!(d, Err(Error::Overflow));
+
+    let d = parser.parse_from("18446744073709551615 months", UNIX_EPOCH);
+    assert_eq!(d, Err(Error::Overflow));
+
+    let d = parser.parse_from("1000000000000 years", UNIX_EPOCH);
+    assert_eq!(d, Err(Error::Overflow));
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "std")]
+
+use std::time::Duration;
+
+use durstr::{Error, Parser, ParserOptions, ParserUnits, Unit, lexer::Quantity, parse_clauses};
+
+#[test]
+fn test_parsing_clauses() {
+    let clauses = parse_clauses(" 1 hr, 30min 5s.").unwrap();
+    assert_eq!(clauses.len(), 3);
+
+    assert_eq!(clauses[0].value(), Quantity::from(1));
+    assert_eq!(clauses[0].unit(), Some(Unit::Hour));
+    assert_eq!(clauses[0].duration(), Duration::from_secs(3600));
+    assert_eq!(clauses[0].span(), 1..5);
+    assert_eq!(clauses[0].text(), "1 hr");
+
+    assert_eq!(clauses[1].unit(), Some(Unit::Minute));
+    assert_eq!(clauses[1].text(), "30min");
+
+    assert_eq!(clauses[2].unit(), Some(Unit::Second));
+    assert_eq!(clauses[2].span(), 13..15);
+
+    assert_eq!(parse_clauses(""), Ok(vec![]));
+
+    let clauses = parse_clauses("1h 2x");
+    assert_eq!(clauses, Err(Error::UnexpectedUnit("x".to_owned())));
+}
+
+#[test]
+fn test_parsing_clauses_with_options() {
+    let mut units = ParserUnits::default();
+    units.add_unit("d", Duration::from_secs(86400));
+
+    let parser = Parser::new(ParserOptions {
+        units,
+        allow_fractions: true,
+        ..Default::default()
+    });
+
+    let clauses = parser.parse_clauses("1.5d 0.5 MIN").unwrap_err();
+    assert_eq!(clauses, Error::UnexpectedUnit("MIN".to_owned()));
+
+    let clauses = parser.parse_clauses("1.5d 0.5min").unwrap();
+    assert_eq!(
+        clauses[0].value(),
+        Quantity {
+            whole: 1,
+            frac: Some(500_000_000)
+        }
+    );
+    assert_eq!(clauses[0].unit(), None);
+    assert_eq!(clauses[0].duration(), Duration::from_secs(129600));
+    assert_eq!(clauses[1].unit(), Some(Unit::Minute));
+    assert_eq!(clauses[1].duration(), Duration::from_secs(30));
+}
+
+#[test]
+fn test_clause_units_come_from_the_unit_table() {
+    let mut units = ParserUnits::default();
+    units.add_unit("tick", Duration::from_secs(1));
+    units.add_unit("m", Duration::from_secs(60 * 60 * 24 * 30));
+
+    let parser = Parser::new(ParserOptions {
+        units,
+        correct_typos: true,
+        ..Default::default()
+    });
+
+    // a custom unit of the same length as a second is not a second
+    let clauses = parser.parse_clauses("2 tick 1 m 3 minuts").unwrap();
+    assert_eq!(clauses[0].unit(), None);
+    assert_eq!(clauses[0].unit_text(), "tick");
+    assert_eq!(clauses[0].unit_span(), 2..6);
+    assert_eq!(clauses[1].unit(), None);
+    assert_eq!(clauses[2].unit(), Some(Unit::Minute));
+    assert_eq!(clauses[2].unit_text(), "minuts");
+}
+
+#[test]
+fn test_clause_units_from_spec() {
+    let parser = Parser::new(ParserOptions::from_spec("units=s|m|h").unwrap());
+
+    let clauses = parser.parse_clauses("5 s 2 m").unwrap();
+    assert_eq!(clauses[0].unit(), Some(Unit::Second));
+    assert_eq!(clauses[1].unit(), Some(Unit::Minute));
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn test_clause_units_in_other_languages() {
+    use durstr::Locale;
+
+    let parser = Parser::new(ParserOptions {
+        units: ParserUnits::for_locale(Locale::Polish),
+        ..Default::default()
+    });
+
+    let clauses = parser.parse_clauses("2 godziny 5 minut").unwrap();
+    assert_eq!(clauses[0].unit(), Some(Unit::Hour));
+    assert_eq!(clauses[1].unit(), Some(Unit::Minute));
+}
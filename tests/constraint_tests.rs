@@ -0,0 +1,53 @@
+#![cfg(feature = "constraint")]
+
+use std::time::Duration;
+
+use durstr::{Comparison, Error, Parser, ParserOptions, parse_constraint};
+
+#[test]
+fn test_parsing_constraints() {
+    let p = parse_constraint(">= 5 min").unwrap();
+    assert_eq!(
+        p.comparisons(),
+        [(Comparison::GreaterOrEqual, Duration::from_secs(300))]
+    );
+    assert!(p.eval(Duration::from_secs(300)));
+    assert!(!p.eval(Duration::from_secs(299)));
+
+    let p = parse_constraint("> 1s and < 30s").unwrap();
+    assert!(p.eval(Duration::from_secs(15)));
+    assert!(!p.eval(Duration::from_secs(1)));
+    assert!(!p.eval(Duration::from_secs(30)));
+
+    let p = parse_constraint("<1h30m").unwrap();
+    assert_eq!(
+        p.comparisons(),
+        [(Comparison::Less, Duration::from_secs(5400))]
+    );
+
+    let p = parse_constraint("= 1m and == 60s and <= 1 minute").unwrap();
+    assert!(p.eval(Duration::from_secs(60)));
+    assert!(!p.eval(Duration::from_secs(59)));
+
+    let p = parse_constraint("5 min");
+    assert_eq!(p, Err(Error::ExpectedComparison));
+
+    let p = parse_constraint("> 1s and");
+    assert_eq!(p, Err(Error::ExpectedComparison));
+
+    let p = parse_constraint(">");
+    assert_eq!(p, Err(Error::ExpectedNumber));
+
+    let p = parse_constraint("> 1s or < 5s");
+    assert_eq!(p, Err(Error::UnexpectedChar('<')));
+
+    let p = parse_constraint("> 1s AND < 5s");
+    assert_eq!(p, Err(Error::UnexpectedChar('<')));
+
+    let parser = Parser::new(ParserOptions {
+        ignore_case: true,
+        ..Default::default()
+    });
+    let p = parser.parse_constraint("> 1S AND < 5S").unwrap();
+    assert!(p.eval(Duration::from_secs(2)));
+}
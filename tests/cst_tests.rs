@@ -0,0 +1,50 @@
+#![cfg(feature = "cst")]
+
+use std::time::Duration;
+
+use durstr::{Error, Parser, ParserOptions, parse_cst};
+
+#[test]
+fn test_parsing_cst() {
+    for input in ["1h 30m", "  1 hr,\t30 min.  ", "2 hrs. 30 min.", "", "0s"] {
+        assert_eq!(parse_cst(input).unwrap().to_string(), input);
+    }
+
+    let cst = parse_cst(" 1 hr, 30min 5s ").unwrap();
+    assert_eq!(cst.duration(), Duration::from_secs(5405));
+
+    let components = cst.components();
+    assert_eq!(components.len(), 3);
+    assert_eq!(components[0].text(), "1 hr");
+    assert_eq!(components[0].unit_text(), "hr");
+    assert_eq!(components[0].span(), 1..5);
+    assert_eq!(components[0].duration(), Duration::from_secs(3600));
+    assert_eq!(components[1].text(), "30min");
+    assert_eq!(components[2].span(), 13..15);
+
+    let cst = parse_cst("1h 2x");
+    assert_eq!(cst, Err(Error::UnexpectedUnit("x".to_owned())));
+
+    let cst = parse_cst("every 5m");
+    assert_eq!(cst, Err(Error::ExpectedNumber));
+
+    let cst = parse_cst("18446744073709551615s 1s");
+    assert_eq!(cst, Err(Error::Overflow));
+}
+
+#[test]
+fn test_editing_cst() {
+    let parser = Parser::new(ParserOptions {
+        ignore_case: true,
+        ..Default::default()
+    });
+    let mut cst = parser.parse_cst("1 Hour,  30 MIN").unwrap();
+    cst.replace_component(1, "45 MIN");
+    assert_eq!(cst.to_string(), "1 Hour,  45 MIN");
+    assert_eq!(cst.components()[1].duration(), Duration::from_secs(1800));
+
+    let mut cst = parse_cst("1h\n30m\n").unwrap();
+    cst.replace_component(0, "2h");
+    cst.replace_component(1, "0m");
+    assert_eq!(cst.to_string(), "2h\n0m\n");
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "debug")]
+
+use std::time::Duration;
+
+use durstr::{Error, debug};
+
+#[test]
+fn test_parsing_debug_output() {
+    let d = debug::parse("1.5s");
+    assert_eq!(d, Ok(Duration::from_millis(1500)));
+
+    let d = debug::parse("300ms");
+    assert_eq!(d, Ok(Duration::from_millis(300)));
+
+    let d = debug::parse("2.3µs");
+    assert_eq!(d, Ok(Duration::from_nanos(2300)));
+
+    let d = debug::parse("2.3μs");
+    assert_eq!(d, Ok(Duration::from_nanos(2300)));
+
+    let d = debug::parse("7us");
+    assert_eq!(d, Ok(Duration::from_micros(7)));
+
+    let d = debug::parse("0ns");
+    assert_eq!(d, Ok(Duration::ZERO));
+
+    let d = debug::parse("2.5 min");
+    assert_eq!(d, Err(Error::UnexpectedUnit("min".to_owned())));
+
+    let d = debug::parse("1.5ps");
+    assert_eq!(d, Err(Error::UnexpectedUnit("ps".to_owned())));
+}
+
+#[test]
+fn test_debug_output_round_trip() {
+    let durations = [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::from_nanos(999),
+        Duration::from_nanos(1_001),
+        Duration::from_micros(1_500),
+        Duration::from_millis(999),
+        Duration::new(1, 1),
+        Duration::new(3723, 62_000_000),
+        Duration::MAX,
+    ];
+
+    for d in durations {
+        assert_eq!(debug::parse(&format!("{d:?}")), Ok(d), "{d:?}");
+    }
+}
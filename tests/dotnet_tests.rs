@@ -0,0 +1,56 @@
+#![cfg(feature = "dotnet")]
+
+use std::time::Duration;
+
+use durstr::{
+    Error,
+    dotnet::{format_timespan, parse_timespan},
+};
+
+#[test]
+fn test_parsing_timespans() {
+    let d = parse_timespan("1.02:03:04.5");
+    assert_eq!(d, Ok(Duration::from_millis(93_784_500)));
+
+    let d = parse_timespan("02:03");
+    assert_eq!(d, Ok(Duration::from_secs(7380)));
+
+    let d = parse_timespan(" 00:00:01.0000001 ");
+    assert_eq!(d, Ok(Duration::new(1, 100)));
+
+    let d = parse_timespan("3");
+    assert_eq!(d, Ok(Duration::from_secs(3 * 86400)));
+
+    let d = parse_timespan("24:00:00");
+    assert_eq!(d, Err(Error::OutOfRange("24".to_owned())));
+
+    let d = parse_timespan("00:60");
+    assert_eq!(d, Err(Error::OutOfRange("60".to_owned())));
+
+    let d = parse_timespan("00:00:00.12345678");
+    assert_eq!(d, Err(Error::OutOfRange("12345678".to_owned())));
+
+    let d = parse_timespan("-01:00:00");
+    assert_eq!(d, Err(Error::UnexpectedChar('-')));
+
+    let d = parse_timespan("01::00");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parse_timespan("1h");
+    assert_eq!(d, Err(Error::UnexpectedChar('h')));
+}
+
+#[test]
+fn test_formatting_timespans() {
+    assert_eq!(format_timespan(Duration::ZERO), "00:00:00");
+    assert_eq!(format_timespan(Duration::from_secs(7380)), "02:03:00");
+    assert_eq!(
+        format_timespan(Duration::from_millis(93_784_500)),
+        "1.02:03:04.5000000"
+    );
+    assert_eq!(format_timespan(Duration::new(1, 199)), "00:00:01.0000001");
+
+    for s in ["1.02:03:04.5000000", "10.00:00:00", "23:59:59.9999999"] {
+        assert_eq!(format_timespan(parse_timespan(s).unwrap()), s);
+    }
+}
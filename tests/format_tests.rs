@@ -0,0 +1,317 @@
+#![cfg(feature = "format")]
+
+use std::{fmt::Alignment, time::Duration};
+
+use durstr::{Formatter, FormatterOptions, Parser, ParserOptions, Unit, UnitStyle, format, parse};
+
+#[test]
+fn test_formatting() {
+    assert_eq!(format(Duration::ZERO), "0 seconds");
+    assert_eq!(format(Duration::from_secs(1)), "1 second");
+    assert_eq!(
+        format(Duration::from_secs(3723)),
+        "1 hour 2 minutes 3 seconds"
+    );
+    assert_eq!(
+        format(Duration::from_millis(7_200_045)),
+        "2 hours 45 milliseconds"
+    );
+    assert_eq!(format(Duration::from_nanos(999_999)), "0 seconds");
+}
+
+#[test]
+fn test_formatting_styles() {
+    let d = Duration::from_millis(3_723_004);
+
+    let formatter = |style, pluralize| {
+        Formatter::new(FormatterOptions {
+            style,
+            pluralize,
+            ..Default::default()
+        })
+    };
+
+    assert_eq!(formatter(UnitStyle::Symbol, true).format(d), "1h 2m 3s 4ms");
+    assert_eq!(
+        formatter(UnitStyle::Short, true).format(d),
+        "1 hr 2 mins 3 secs 4 msecs"
+    );
+    assert_eq!(
+        formatter(UnitStyle::Short, false).format(d),
+        "1 hr 2 min 3 sec 4 msec"
+    );
+    assert_eq!(
+        formatter(UnitStyle::Long, false).format(d),
+        "1 hour 2 minute 3 second 4 millisecond"
+    );
+
+    for style in [UnitStyle::Symbol, UnitStyle::Short, UnitStyle::Long] {
+        for pluralize in [true, false] {
+            assert_eq!(parse(&formatter(style, pluralize).format(d)), Ok(d));
+        }
+    }
+}
+
+#[test]
+fn test_formatting_largest_unit() {
+    let formatter = |largest_unit| {
+        Formatter::new(FormatterOptions {
+            largest_unit: Some(largest_unit),
+            ..Default::default()
+        })
+    };
+
+    let d = Duration::from_secs(9000);
+    assert_eq!(formatter(Unit::Hour).format(d), "2 hours 30 minutes");
+    assert_eq!(formatter(Unit::Minute).format(d), "150 minutes");
+    assert_eq!(formatter(Unit::Second).format(d), "9000 seconds");
+    assert_eq!(
+        formatter(Unit::Millisecond).format(d),
+        "9000000 milliseconds"
+    );
+
+    assert_eq!(formatter(Unit::Minute).format(Duration::ZERO), "0 seconds");
+    assert_eq!(
+        formatter(Unit::Millisecond).format(Duration::ZERO),
+        "0 milliseconds"
+    );
+}
+
+#[test]
+fn test_formatting_padding() {
+    let formatter = Formatter::new(FormatterOptions {
+        style: UnitStyle::Symbol,
+        largest_unit: Some(Unit::Hour),
+        smallest_unit: Some(Unit::Minute),
+        zero_pad: true,
+        width: Some(8),
+        ..Default::default()
+    });
+
+    let d = Duration::from_secs(3725);
+    assert_eq!(formatter.format(d), "  1h 02m");
+    assert_eq!(formatter.format(Duration::from_secs(300)), "  0h 05m");
+    assert_eq!(formatter.format(Duration::ZERO), "  0h 00m");
+    assert_eq!(formatter.format(Duration::from_secs(36000)), " 10h 00m");
+    assert_eq!(formatter.format(Duration::from_secs(360000)), "100h 00m");
+    assert_eq!(formatter.format(Duration::from_secs(3600000)), "1000h 00m");
+
+    let formatter = Formatter::new(FormatterOptions {
+        style: UnitStyle::Symbol,
+        zero_pad: true,
+        ..Default::default()
+    });
+    assert_eq!(formatter.format(Duration::from_secs(62)), "1m 02s 000ms");
+    assert_eq!(formatter.format(Duration::from_millis(5)), "5ms");
+
+    let formatter = |align| {
+        Formatter::new(FormatterOptions {
+            smallest_unit: Some(Unit::Second),
+            width: Some(12),
+            align,
+            ..Default::default()
+        })
+    };
+    let d = Duration::from_millis(1500);
+    assert_eq!(formatter(Alignment::Left).format(d), "1 second    ");
+    assert_eq!(formatter(Alignment::Right).format(d), "    1 second");
+    assert_eq!(formatter(Alignment::Center).format(d), "  1 second  ");
+    assert_eq!(
+        formatter(Alignment::Left).format(Duration::from_millis(400)),
+        "0 seconds   "
+    );
+}
+
+#[test]
+fn test_formatting_decimal() {
+    let formatter = |places, largest_unit| {
+        Formatter::new(FormatterOptions {
+            style: UnitStyle::Symbol,
+            decimal_places: Some(places),
+            largest_unit,
+            ..Default::default()
+        })
+    };
+
+    assert_eq!(formatter(1, None).format(Duration::from_secs(5400)), "1.5h");
+    assert_eq!(
+        formatter(2, None).format(Duration::from_millis(90250)),
+        "1.50m"
+    );
+    let d = Duration::from_millis(90250);
+    assert_eq!(formatter(2, Some(Unit::Second)).format(d), "90.25s");
+    assert_eq!(formatter(1, Some(Unit::Second)).format(d), "90.3s");
+    assert_eq!(formatter(0, Some(Unit::Second)).format(d), "90s");
+    assert_eq!(
+        formatter(3, None).format(Duration::from_nanos(1500)),
+        "0.002ms"
+    );
+    assert_eq!(formatter(2, None).format(Duration::ZERO), "0.00s");
+    assert_eq!(
+        formatter(20, None).format(Duration::from_nanos(1)),
+        "0.000001000ms"
+    );
+
+    let formatter = |places| {
+        Formatter::new(FormatterOptions {
+            decimal_places: Some(places),
+            ..Default::default()
+        })
+    };
+    assert_eq!(formatter(0).format(Duration::from_secs(60)), "1 minute");
+    assert_eq!(formatter(1).format(Duration::from_secs(60)), "1.0 minutes");
+    assert_eq!(formatter(0).format(Duration::from_secs(150)), "3 minutes");
+}
+
+#[test]
+fn test_formatting_samples() {
+    let formatter = Formatter::new(FormatterOptions {
+        sample_rate: Some(48000),
+        ..Default::default()
+    });
+    assert_eq!(formatter.format(Duration::from_millis(100)), "4800 samples");
+    assert_eq!(formatter.format(Duration::from_nanos(30000)), "1 sample");
+
+    let formatter = Formatter::new(FormatterOptions {
+        sample_rate: Some(44100),
+        ..Default::default()
+    });
+    let parser = Parser::new(ParserOptions {
+        sample_rate: Some(44100),
+        ..Default::default()
+    });
+    for n in [2, 441, 44099, 123_456_789] {
+        let s = format!("{n} samples");
+        assert_eq!(formatter.format(parser.parse(&s).unwrap()), s);
+    }
+}
+
+#[test]
+#[cfg(feature = "locale")]
+fn test_formatting_locales() {
+    use durstr::Locale;
+
+    let formatter = |locale| {
+        Formatter::new(FormatterOptions {
+            locale,
+            ..Default::default()
+        })
+    };
+    let minutes = |n: u64| Duration::from_secs(60 * n);
+
+    let russian = formatter(Locale::Russian);
+    assert_eq!(russian.format(minutes(1)), "1 минута");
+    assert_eq!(russian.format(minutes(2)), "2 минуты");
+    assert_eq!(russian.format(minutes(5)), "5 минут");
+    assert_eq!(russian.format(minutes(11)), "11 минут");
+    assert_eq!(russian.format(minutes(21)), "21 минута");
+    assert_eq!(russian.format(minutes(22)), "22 минуты");
+    assert_eq!(russian.format(Duration::from_secs(7320)), "2 часа 2 минуты");
+    assert_eq!(russian.format(Duration::ZERO), "0 секунд");
+
+    let polish = formatter(Locale::Polish);
+    assert_eq!(polish.format(minutes(1)), "1 minuta");
+    assert_eq!(polish.format(minutes(3)), "3 minuty");
+    assert_eq!(polish.format(minutes(12)), "12 minut");
+    assert_eq!(polish.format(minutes(21)), "21 minut");
+    assert_eq!(polish.format(minutes(24)), "24 minuty");
+
+    let arabic = formatter(Locale::Arabic);
+    assert_eq!(arabic.format(minutes(2)), "2 دقيقتان");
+    assert_eq!(arabic.format(minutes(5)), "5 دقائق");
+    assert_eq!(arabic.format(minutes(15)), "15 دقيقة");
+
+    let hebrew = formatter(Locale::Hebrew);
+    assert_eq!(hebrew.format(Duration::from_secs(3660)), "1 שעה 1 דקה");
+    assert_eq!(hebrew.format(minutes(2)), "2 דקות");
+
+    let symbols = Formatter::new(FormatterOptions {
+        locale: Locale::Russian,
+        style: UnitStyle::Symbol,
+        ..Default::default()
+    });
+    assert_eq!(symbols.format(minutes(5)), "5m");
+}
+
+#[test]
+#[cfg(feature = "calendar")]
+fn test_formatting_between() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // days since the unix epoch
+    let date = |days: u64| UNIX_EPOCH + Duration::from_secs(days * 86400);
+    let formatter = Formatter::default();
+    let between = |a: SystemTime, b: SystemTime| formatter.format_between(a, b);
+
+    // 2024-01-31 plus a month is clamped to 2024-02-29
+    assert_eq!(between(date(19753), date(19782)), "1 month");
+    assert_eq!(between(date(19753), date(19781)), "28 days");
+    // 2024-01-31 to 2024-03-01
+    assert_eq!(between(date(19753), date(19783)), "1 month 1 day");
+    // 2023-03-01 to 2024-03-01, and back
+    assert_eq!(between(date(19417), date(19783)), "1 year");
+    assert_eq!(between(date(19783), date(19417)), "1 year");
+    // 2024-02-01 to 2024-03-01 is one month of 29 days
+    assert_eq!(between(date(19754), date(19783)), "1 month");
+
+    let start = date(19754) + Duration::from_secs(3600);
+    assert_eq!(between(start, date(19783)), "28 days 23 hours");
+    assert_eq!(between(start, start), "0 seconds");
+
+    let formatter = Formatter::new(FormatterOptions {
+        style: UnitStyle::Symbol,
+        ..Default::default()
+    });
+    let end = date(19417 + 366 + 365 + 31 + 2) + Duration::from_secs(90);
+    assert_eq!(
+        formatter.format_between(date(19417), end),
+        "2y 1mo 2d 1m 30s"
+    );
+}
+
+#[test]
+#[cfg(feature = "locale")]
+fn test_unit_labels() {
+    use durstr::{Locale, PluralCategory};
+
+    let locales = [
+        Locale::English,
+        Locale::Arabic,
+        Locale::Hebrew,
+        Locale::Polish,
+        Locale::Russian,
+    ];
+    let styles = [UnitStyle::Symbol, UnitStyle::Short, UnitStyle::Long];
+
+    // labels match the names the formatter uses
+    for (locale, style, unit) in locales
+        .into_iter()
+        .flat_map(|l| styles.map(|s| (l, s)))
+        .flat_map(|(l, s)| Unit::ALL.map(|u| (l, s, u)))
+    {
+        let formatter = Formatter::new(FormatterOptions {
+            locale,
+            style,
+            largest_unit: Some(unit),
+            ..Default::default()
+        });
+        for count in [1, 2, 5, 11, 22, 101] {
+            let formatted = formatter.format(unit.duration() * count);
+            let label = unit.label(u128::from(count), locale, style);
+            assert!(formatted.ends_with(label), "{formatted} {label}");
+        }
+    }
+
+    assert_eq!(
+        Unit::Hour.label(0, Locale::English, UnitStyle::Short),
+        "hrs"
+    );
+    assert_eq!(
+        Unit::Second.label(2, Locale::Hebrew, UnitStyle::Long),
+        "שניות"
+    );
+    assert_eq!(
+        Unit::Minute.localized_name(Locale::Russian, PluralCategory::Other),
+        "минуты"
+    );
+}
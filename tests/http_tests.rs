@@ -0,0 +1,48 @@
+#![cfg(feature = "http")]
+
+use std::time::Duration;
+
+use durstr::{Error, http};
+
+#[test]
+fn test_parsing_cache_control() {
+    let header = "public, Max-Age=3600, stale-while-revalidate=\"60\", no-transform";
+    assert_eq!(http::max_age(header), Ok(Some(Duration::from_secs(3600))));
+    assert_eq!(
+        http::stale_while_revalidate(header),
+        Ok(Some(Duration::from_secs(60)))
+    );
+    assert_eq!(http::s_maxage(header), Ok(None));
+    assert_eq!(http::stale_if_error("no-store"), Ok(None));
+
+    assert_eq!(http::max_age("max-age"), Err(Error::ExpectedNumber));
+    assert_eq!(http::max_age("max-age=1h"), Err(Error::UnexpectedChar('h')));
+    assert_eq!(http::max_age("max-age=-1"), Err(Error::UnexpectedChar('-')));
+}
+
+#[test]
+fn test_retry_after() {
+    assert_eq!(
+        http::parse_retry_after(" 120 "),
+        Ok(Duration::from_secs(120))
+    );
+    assert_eq!(
+        http::parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+        Err(Error::UnexpectedChar('W'))
+    );
+
+    assert_eq!(http::format_retry_after(Duration::from_secs(120)), "120");
+    assert_eq!(http::format_retry_after(Duration::from_millis(1500)), "2");
+    assert_eq!(
+        http::format_retry_after(Duration::MAX),
+        u64::MAX.to_string()
+    );
+    assert_eq!(
+        http::format_max_age(Duration::from_millis(1500)),
+        "max-age=1"
+    );
+    assert_eq!(
+        http::format_cache_control_directive("s-maxage", Duration::from_secs(30)),
+        "s-maxage=30"
+    );
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "format")]
+
+use std::time::Duration;
+
+use durstr::{Error, HumanDuration, Parser, ParserOptions};
+
+fn human(s: &str) -> HumanDuration {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_human_duration_display() {
+    for input in ["90m", "1h 30m", "2 hrs 5 mins", "1 hour", "0s", "1h 0m"] {
+        let expected = input.replace(" 0m", "");
+        assert_eq!(human(input).to_string(), expected);
+    }
+
+    assert_eq!(human("30m 1h").to_string(), "1h 30m");
+    assert_eq!(human("90 s").duration(), Duration::from_secs(90));
+    assert_eq!(human("").to_string(), "0 seconds");
+
+    let d = "1h 2x".parse::<HumanDuration>();
+    assert_eq!(d, Err(Error::UnexpectedUnit("x".to_owned())));
+}
+
+#[test]
+fn test_human_duration_arithmetic() {
+    assert_eq!((human("90m") + human("30m")).to_string(), "120m");
+    assert_eq!((human("1h") + human("30m")).to_string(), "1h 30m");
+    assert_eq!((human("1h") - human("30m")).to_string(), "30m");
+    assert_eq!((human("1h 30m") - human("30m")).to_string(), "1h");
+    assert_eq!((human("1h") - human("1h")).to_string(), "0h");
+    assert_eq!((human("45s") * 4).to_string(), "180s");
+    assert_eq!((human("1 hour") * 2).to_string(), "2 hours");
+    assert_eq!((human("2 mins") - human("1min")).to_string(), "1 min");
+    assert_eq!(
+        (human("1 hour") + human("1 minute")).to_string(),
+        "1 hour 1 minute"
+    );
+
+    let d = human("1h") + human("30m");
+    assert_eq!(Duration::from(d), Duration::from_secs(5400));
+}
+
+#[test]
+fn test_human_duration_fallback() {
+    let parser = Parser::new(ParserOptions {
+        allow_fractions: true,
+        ..Default::default()
+    });
+
+    let d = parser.parse_human("1.5h").unwrap();
+    assert_eq!(d.to_string(), "1 hour 30 minutes");
+    assert_eq!((d * 2).to_string(), "3h");
+}
+
+#[test]
+#[should_panic]
+fn test_human_duration_underflow() {
+    let _ = human("1m") - human("1h");
+}
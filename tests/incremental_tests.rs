@@ -0,0 +1,75 @@
+#![cfg(feature = "std")]
+
+use std::time::Duration;
+
+use durstr::{Error, IncrementalParser, Parser, ParserOptions, Warning};
+
+fn parser() -> Parser {
+    Parser::new(ParserOptions {
+        allow_fractions: true,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_incremental_typing() {
+    let input = "1 hr. 30 min, 1.5s";
+    let mut field = IncrementalParser::new(parser());
+    assert_eq!(field.result(), Ok(Duration::ZERO));
+
+    for (i, c) in input.char_indices() {
+        field.insert(i, c.encode_utf8(&mut [0; 4]));
+        assert_eq!(field.text(), &input[..i + c.len_utf8()]);
+        assert_eq!(field.result(), parser().parse(field.text()));
+    }
+    assert_eq!(field.result(), Ok(Duration::from_millis(5401500)));
+
+    while !field.text().is_empty() {
+        field.delete(field.text().len() - 1..field.text().len());
+        assert_eq!(field.result(), parser().parse(field.text()));
+    }
+}
+
+#[test]
+fn test_incremental_edits() {
+    let mut text = String::from("12 minutes 5 s");
+    let mut field = IncrementalParser::new(parser());
+    field.insert(0, &text);
+
+    // edits inside, right before and right after tokens
+    let edits = [
+        (2..2, "0"),
+        (14..15, "ms"),
+        (4..4, "5"),
+        (4..5, ""),
+        (13..13, ".٥"),
+        (0..0, "x"),
+        (0..1, "1h "),
+        (5..5, "."),
+        (0..3, ""),
+    ];
+    for (range, edit) in edits {
+        text.replace_range(range.clone(), edit);
+        field.replace(range, edit);
+        assert_eq!(field.text(), text);
+        assert_eq!(field.result(), parser().parse(&text), "{text}");
+    }
+    assert_eq!(field.text(), "12.0 minutes 5.٥ ms");
+    assert_eq!(field.result(), Ok(Duration::from_micros(720_005_500)));
+}
+
+#[test]
+fn test_incremental_diagnostics() {
+    let mut field = IncrementalParser::new(parser());
+    field.insert(0, "1m 30s 20s");
+    assert_eq!(field.warnings(), [Warning::RepeatedUnit("s".to_owned())]);
+
+    field.insert(6, " ?");
+    assert_eq!(field.result(), Err(Error::UnexpectedChar('?')));
+    assert_eq!(field.warnings(), []);
+
+    field.delete(6..8);
+    field.delete(6..10);
+    assert_eq!(field.result(), Ok(Duration::from_secs(90)));
+    assert_eq!(field.warnings(), []);
+}
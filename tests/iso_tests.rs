@@ -0,0 +1,131 @@
+#![cfg(feature = "iso")]
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use durstr::{
+    Error,
+    iso::{self, Repeat},
+};
+
+const DAY: u64 = 86400;
+
+#[test]
+fn test_parsing_iso_durations() {
+    let d = iso::parse_duration("PT1H30M");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = iso::parse_duration("P1DT2H3M4S");
+    assert_eq!(d, Ok(Duration::from_secs(DAY + 7384)));
+
+    let d = iso::parse_duration("P2W");
+    assert_eq!(d, Ok(Duration::from_secs(14 * DAY)));
+
+    let d = iso::parse_duration("PT1.5S");
+    assert_eq!(d, Ok(Duration::from_millis(1500)));
+
+    let d = iso::parse_duration("PT0,25H");
+    assert_eq!(d, Ok(Duration::from_secs(900)));
+
+    let d = iso::parse_duration("P0D");
+    assert_eq!(d, Ok(Duration::ZERO));
+}
+
+#[test]
+fn test_iso_duration_errors() {
+    assert_eq!(iso::parse_duration("P"), Err(Error::ExpectedNumber));
+    assert_eq!(iso::parse_duration("P1DT"), Err(Error::ExpectedNumber));
+    assert_eq!(iso::parse_duration("PT5"), Err(Error::ExpectedUnit));
+    assert_eq!(iso::parse_duration("1H"), Err(Error::UnexpectedChar('1')));
+
+    // components out of order, or in the wrong part
+    let d = iso::parse_duration("PT1S2M");
+    assert_eq!(d, Err(Error::UnexpectedUnit("M".to_owned())));
+    let d = iso::parse_duration("P1H");
+    assert_eq!(d, Err(Error::UnexpectedUnit("H".to_owned())));
+
+    // only the last component may have a fraction
+    let d = iso::parse_duration("PT1.5H30M");
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+
+    // years and months need a date
+    let d = iso::parse_duration("P1Y");
+    assert_eq!(d, Err(Error::UnexpectedUnit("Y".to_owned())));
+    let d = iso::parse_duration("P1M");
+    assert_eq!(d, Err(Error::UnexpectedUnit("M".to_owned())));
+}
+
+#[test]
+fn test_parsing_iso_intervals() {
+    let jan_1 = UNIX_EPOCH + Duration::from_secs(19723 * DAY);
+
+    let interval = iso::parse_interval("2024-01-01T00:00Z/P1D").unwrap();
+    assert_eq!(interval.start(), Some(jan_1));
+    assert_eq!(interval.end(), Some(jan_1 + Duration::from_secs(DAY)));
+    assert_eq!(interval.duration(), Duration::from_secs(DAY));
+    assert_eq!(interval.repeat(), None);
+
+    let interval = iso::parse_interval("P1D/2024-02-01T00:00Z").unwrap();
+    assert_eq!(
+        interval.start(),
+        Some(jan_1 + Duration::from_secs(30 * DAY))
+    );
+    assert_eq!(interval.duration(), Duration::from_secs(DAY));
+
+    let interval = iso::parse_interval("2024-01-01/2024-01-02T12:00Z").unwrap();
+    assert_eq!(interval.duration(), Duration::from_secs(DAY + 43200));
+
+    // offsets are converted to UTC
+    let interval = iso::parse_interval("2024-01-01T02:00+02:00/PT1H").unwrap();
+    assert_eq!(interval.start(), Some(jan_1));
+    let interval = iso::parse_interval("2023-12-31T19:00-0500/PT1H").unwrap();
+    assert_eq!(interval.start(), Some(jan_1));
+}
+
+#[test]
+fn test_iso_intervals_with_months() {
+    // January has 31 days, and February 2024 has 29
+    let interval = iso::parse_interval("2024-01-01T00:00Z/P1M").unwrap();
+    assert_eq!(interval.duration(), Duration::from_secs(31 * DAY));
+
+    let interval = iso::parse_interval("P1M/2024-03-01T00:00Z").unwrap();
+    assert_eq!(interval.duration(), Duration::from_secs(29 * DAY));
+
+    let interval = iso::parse_interval("2024-01-01T00:00Z/P1Y").unwrap();
+    assert_eq!(interval.duration(), Duration::from_secs(366 * DAY));
+
+    let d = iso::parse_interval("P1M");
+    assert_eq!(d, Err(Error::UnexpectedUnit("M".to_owned())));
+}
+
+#[test]
+fn test_repeating_iso_intervals() {
+    let interval = iso::parse_interval("R5/PT1H").unwrap();
+    assert_eq!(interval.duration(), Duration::from_secs(3600));
+    assert_eq!(interval.repeat(), Some(Repeat::Times(5)));
+    assert_eq!(interval.start(), None);
+
+    let interval = iso::parse_interval("R/2024-01-01T00:00Z/P1D").unwrap();
+    assert_eq!(interval.repeat(), Some(Repeat::Forever));
+    assert_eq!(interval.duration(), Duration::from_secs(DAY));
+}
+
+#[test]
+fn test_invalid_iso_intervals() {
+    for input in [
+        "PT1H/PT2H",
+        "2024-01-01",
+        "2024-01-02/2024-01-01",
+        "2024-01-01/P1D/P1D",
+        "R5",
+    ] {
+        let d = iso::parse_interval(input);
+        assert_eq!(d, Err(Error::InvalidInterval(input.to_owned())));
+    }
+
+    let d = iso::parse_interval("2024-02-30/P1D");
+    assert_eq!(d, Err(Error::OutOfRange("30".to_owned())));
+    let d = iso::parse_interval("2024-01-01T25:00Z/P1D");
+    assert_eq!(d, Err(Error::OutOfRange("25".to_owned())));
+    let d = iso::parse_interval("2024-01-01T00:00+1é/P1D");
+    assert_eq!(d, Err(Error::UnexpectedChar('é')));
+}
@@ -0,0 +1,51 @@
+use durstr::lexer::{LexError, Lexer, Quantity, Token, TokenKind};
+
+#[test]
+fn test_lexer_spans() {
+    let tokens: Result<Vec<_>, _> = Lexer::new("1h, 30 min. 2.5µs").collect();
+    assert_eq!(
+        tokens,
+        Ok(vec![
+            Token {
+                kind: TokenKind::Number(1.into()),
+                span: 0..1
+            },
+            Token {
+                kind: TokenKind::Unit("h"),
+                span: 1..2
+            },
+            Token {
+                kind: TokenKind::Number(30.into()),
+                span: 4..6
+            },
+            Token {
+                kind: TokenKind::Unit("min"),
+                span: 7..10
+            },
+            Token {
+                kind: TokenKind::Number(Quantity {
+                    whole: 2,
+                    frac: Some(500_000_000)
+                }),
+                span: 12..15
+            },
+            Token {
+                kind: TokenKind::Unit("µs"),
+                span: 15..18
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_lexer_errors() {
+    let mut lexer = Lexer::new("1 * 99999999999999999999 s");
+    assert_eq!(
+        lexer.next().unwrap().map(|t| t.kind),
+        Ok(TokenKind::Number(1.into()))
+    );
+    assert_eq!(lexer.next(), Some(Err(LexError::UnexpectedChar('*'))));
+    assert_eq!(lexer.next(), Some(Err(LexError::Overflow)));
+    assert_eq!(lexer.next().unwrap().map(|t| t.span), Ok(25..26));
+    assert_eq!(lexer.next(), None);
+}
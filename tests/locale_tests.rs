@@ -0,0 +1,112 @@
+#![cfg(feature = "locale")]
+
+use std::time::Duration;
+
+use durstr::{Error, Locale, Parser, ParserOptions, ParserUnits, parse};
+
+fn parser(locale: Locale) -> Parser {
+    Parser::new(ParserOptions {
+        units: ParserUnits::for_locale(locale),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_parsing_arabic() {
+    let parser = parser(Locale::Arabic);
+
+    let d = parser.parse("٥ دقائق");
+    assert_eq!(d, Ok(Duration::from_secs(300)));
+
+    let d = parser.parse("5 دقائق");
+    assert_eq!(d, Ok(Duration::from_secs(300)));
+
+    let d = parser.parse("۲ ساعات");
+    assert_eq!(d, Ok(Duration::from_secs(7200)));
+
+    let d = parser.parse("٢ ساعات، ١٥ دقيقة");
+    assert_eq!(d, Ok(Duration::from_secs(8100)));
+
+    let d = parser.parse("\u{200F}ساعات \u{200E}12\u{200E}");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parser.parse("\u{200E}12\u{200E} ساعه");
+    assert_eq!(d, Ok(Duration::from_secs(43200)));
+
+    let d = parser.parse("١ ساعة 30 min");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = parser.parse("٤٥ ثانية");
+    assert_eq!(d, Ok(Duration::from_secs(45)));
+}
+
+#[test]
+fn test_parsing_hebrew() {
+    let parser = parser(Locale::Hebrew);
+
+    let d = parser.parse("5 דקות");
+    assert_eq!(d, Ok(Duration::from_secs(300)));
+
+    let d = parser.parse("\u{2067}2 שעות ו-30 דקות\u{2069}");
+    assert_eq!(d, Err(Error::UnexpectedChar('-')));
+
+    let d = parser.parse("\u{2067}2 שעות, 30 דקות\u{2069}");
+    assert_eq!(d, Ok(Duration::from_secs(9000)));
+
+    let d = parser.parse("1 שעה 1 שניה 1 שנייה");
+    assert_eq!(d, Ok(Duration::from_secs(3602)));
+}
+
+#[test]
+fn test_parsing_arabic_indic_digits() {
+    let d = parse("١٢ min");
+    assert_eq!(d, Ok(Duration::from_secs(720)));
+
+    let d = parse("۱۲ min");
+    assert_eq!(d, Ok(Duration::from_secs(720)));
+
+    let parser = Parser::new(ParserOptions {
+        units: ParserUnits::for_locale(Locale::Arabic),
+        allow_fractions: true,
+        ..Default::default()
+    });
+    let d = parser.parse("١٫٥ ساعة");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = parse("5 دقائق");
+    assert_eq!(d, Err(Error::UnexpectedUnit("دقائق".to_owned())));
+}
+
+#[test]
+#[cfg(feature = "sys-locale")]
+fn test_system_locale() {
+    // SAFETY: no other test reads or writes these variables
+    unsafe {
+        std::env::remove_var("LC_ALL");
+        std::env::remove_var("LC_MESSAGES");
+        std::env::set_var("LANG", "ru_RU.UTF-8");
+    }
+    assert_eq!(Locale::system(), Locale::Russian);
+
+    let d = Parser::system_locale().parse("5 минут");
+    assert_eq!(d, Ok(Duration::from_secs(300)));
+
+    unsafe { std::env::set_var("LC_ALL", "he_IL.UTF-8") };
+    assert_eq!(Locale::system(), Locale::Hebrew);
+
+    unsafe { std::env::set_var("LC_ALL", "ja_JP.UTF-8") };
+    assert_eq!(Locale::system(), Locale::English);
+
+    unsafe {
+        std::env::set_var("LC_ALL", "");
+        std::env::set_var("LC_MESSAGES", "pl_PL");
+    }
+    assert_eq!(Locale::system(), Locale::Polish);
+
+    unsafe {
+        std::env::remove_var("LC_ALL");
+        std::env::remove_var("LC_MESSAGES");
+        std::env::remove_var("LANG");
+    }
+    assert_eq!(Locale::system(), Locale::English);
+}
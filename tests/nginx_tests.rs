@@ -0,0 +1,52 @@
+#![cfg(feature = "nginx")]
+
+use std::time::Duration;
+
+use durstr::{Error, nginx};
+
+const DAY: u64 = 86400;
+
+#[test]
+fn test_parsing_nginx() {
+    let d = nginx::parse("1h30m");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = nginx::parse("1h 30m");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = nginx::parse("500ms");
+    assert_eq!(d, Ok(Duration::from_millis(500)));
+
+    let d = nginx::parse("1y 2M 1w 3d");
+    assert_eq!(d, Ok(Duration::from_secs(DAY * (365 + 60 + 7 + 3))));
+
+    let d = nginx::parse("60");
+    assert_eq!(d, Ok(Duration::from_secs(60)));
+
+    let d = nginx::parse("1m30");
+    assert_eq!(d, Ok(Duration::from_secs(90)));
+
+    let d = nginx::parse("1m 1M");
+    assert_eq!(d, Err(Error::UnexpectedUnit("M".to_owned())));
+
+    let d = nginx::parse("30m 1h");
+    assert_eq!(d, Err(Error::UnexpectedUnit("h".to_owned())));
+
+    let d = nginx::parse("1h 1h");
+    assert_eq!(d, Err(Error::UnexpectedUnit("h".to_owned())));
+
+    let d = nginx::parse("1H");
+    assert_eq!(d, Err(Error::UnexpectedUnit("H".to_owned())));
+
+    let d = nginx::parse("1h, 30m");
+    assert_eq!(d, Err(Error::UnexpectedChar(',')));
+
+    let d = nginx::parse("1.5h");
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+
+    let d = nginx::parse("1 minute");
+    assert_eq!(d, Err(Error::UnexpectedUnit("minute".to_owned())));
+
+    let d = nginx::parse("m");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+}
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use durstr::no_alloc::{self, Error, Parser};
+
+#[test]
+fn test_parsing_no_alloc() {
+    let d = no_alloc::parse("2 minutes, 12 seconds");
+    assert_eq!(d, Ok(Duration::from_secs(132)));
+
+    let d = no_alloc::parse("1h2min3s62ms");
+    assert_eq!(d, Ok(Duration::from_millis(3723062)));
+
+    let d = no_alloc::parse("2min 1*2 sec");
+    assert_eq!(d, Err(Error::UnexpectedChar('*')));
+
+    let d = no_alloc::parse("2 min 1 r");
+    assert_eq!(d, Err(Error::UnexpectedUnit("r")));
+
+    let d = no_alloc::parse("1 2");
+    assert_eq!(d, Err(Error::ExpectedUnit));
+
+    let d = no_alloc::parse("1 s m");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = no_alloc::parse("1.5 min");
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+
+    let d = no_alloc::parse("99999999999999999999 ms");
+    assert_eq!(d, Err(Error::Overflow));
+
+    let d = no_alloc::parse("1 MIN");
+    assert_eq!(d, Err(Error::UnexpectedUnit("MIN")));
+
+    let parser = Parser {
+        ignore_case: true,
+        ..Default::default()
+    };
+    let d = parser.parse("1 MIN 2 Sec");
+    assert_eq!(d, Ok(Duration::from_secs(62)));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use std::time::Duration;
 
 use durstr::{Error, Parser, ParserOptions, ParserUnits, parse};
@@ -37,6 +39,15 @@ fn test_parsing() {
     let d = parse("1 2");
     assert_eq!(d, Err(Error::ExpectedUnit));
 
+    let d = parse("2 hrs. 30 min.");
+    assert_eq!(d, Ok(Duration::from_secs(9000)));
+
+    let d = parse("2 hrs.. 30 min");
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+
+    let d = parse("2 hrs .30 min");
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+
     let d = parse("1 s m");
     assert_eq!(d, Err(Error::ExpectedNumber));
 }
@@ -87,3 +98,760 @@ fn test_parsing_custom_units() {
     let d = parser.parse("4 day");
     assert_eq!(d, Ok(Duration::from_secs(3600) * 24 * 4));
 }
+
+#[test]
+#[cfg(feature = "colloquial")]
+fn test_parsing_colloquial_units() {
+    let mut units = ParserUnits::default();
+    units.add_colloquial_units();
+    let parser = Parser::new(ParserOptions {
+        units,
+        ..Default::default()
+    });
+
+    let day = Duration::from_secs(86400);
+    let year = Duration::from_secs(31_557_600);
+
+    let d = parser.parse("2 fortnights");
+    assert_eq!(d, Ok(day * 28));
+
+    let d = parser.parse("1 decade");
+    assert_eq!(d, Ok(year * 10));
+
+    let d = parser.parse("2 centuries 1 millennium");
+    assert_eq!(d, Ok(year * 1200));
+
+    let d = parse("1 fortnight");
+    assert_eq!(d, Err(Error::UnexpectedUnit("fortnight".to_owned())));
+}
+
+#[test]
+fn test_parsing_with_info() {
+    let mut units = ParserUnits::default();
+    units.add_unit("fortnight", Duration::from_secs(86400) * 14);
+    units.add_approximate_unit("month", Duration::from_secs(86400) * 30);
+    let parser = Parser::new(ParserOptions {
+        units,
+        ignore_case: true,
+        ..Default::default()
+    });
+
+    let info = parser.parse_with_info("1 Hour 30 MIN").unwrap();
+    assert_eq!(info.duration, Duration::from_secs(5400));
+    assert!(!info.approximate);
+    assert_eq!(info.units, ["hour", "min"]);
+
+    let info = parser.parse_with_info("2 month 1 fortnight").unwrap();
+    assert_eq!(info.duration, Duration::from_secs(86400) * 74);
+    assert!(info.approximate);
+
+    let info = parser.parse_with_info("every 90 seconds").unwrap();
+    assert_eq!(info.duration, Duration::from_secs(90));
+    assert!(info.interval);
+    assert!(!parser.parse_with_info("90 seconds").unwrap().interval);
+
+    let d = parser.parse_with_info("2 months");
+    assert_eq!(d, Err(Error::UnexpectedUnit("months".to_owned())));
+    assert!(!parser.parse_with_info("1 hour").unwrap().recovered);
+
+    let mut units = ParserUnits::default();
+    units.add_approximate_unit("month", Duration::from_secs(86400) * 30);
+    let parser = Parser::new(ParserOptions {
+        units,
+        correct_typos: true,
+        ..Default::default()
+    });
+
+    let info = parser.parse_with_info("1 hour 2 mnoth").unwrap();
+    assert!(info.recovered);
+    assert!(info.approximate);
+    assert_eq!(info.units, ["hour", "month"]);
+}
+
+#[test]
+#[cfg(feature = "quantifiers")]
+fn test_parsing_quantifiers() {
+    use durstr::Quantifiers;
+
+    let mut quantifiers = Quantifiers::default();
+    quantifiers.add_quantifier("several", 7);
+    quantifiers.add_quantifier("a handful", 5);
+    let parser = Parser::new(ParserOptions {
+        quantifiers: Some(quantifiers),
+        ignore_case: true,
+        ..Default::default()
+    });
+
+    let d = parser.parse("a few minutes");
+    assert_eq!(d, Ok(Duration::from_secs(180)));
+
+    let d = parser.parse("A couple of hours");
+    assert_eq!(d, Ok(Duration::from_secs(7200)));
+
+    let d = parser.parse("an hour, several seconds");
+    assert_eq!(d, Ok(Duration::from_secs(3607)));
+
+    let d = parser.parse("a handful of ms");
+    assert_eq!(d, Ok(Duration::from_millis(5)));
+
+    let d = parser.parse("a few");
+    assert_eq!(d, Err(Error::ExpectedUnit));
+
+    let d = parser.parse("some minutes");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parse("a few minutes");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let info = parser.parse_with_info("a few minutes").unwrap();
+    assert_eq!(info.duration, Duration::from_secs(180));
+    assert!(info.approximate);
+    assert!(!parser.parse_with_info("3 minutes").unwrap().approximate);
+}
+
+#[test]
+fn test_parsing_intervals() {
+    let d = parse("every 5 minutes");
+    assert_eq!(d, Ok(Duration::from_secs(300)));
+
+    let d = parse("each hour");
+    assert_eq!(d, Ok(Duration::from_secs(3600)));
+
+    let d = parse("every 1h 30m");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = parse("every");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parse(" each, ");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parse("5 minutes every");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parse("Every hour");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+}
+
+#[test]
+fn test_parsing_into() {
+    use durstr::{FromParsedDuration, parse_into};
+
+    #[derive(Debug, PartialEq)]
+    struct Seconds(u64);
+
+    impl FromParsedDuration for Seconds {
+        fn from_parsed_duration(d: Duration) -> Result<Self, Error> {
+            match d.subsec_nanos() {
+                0 => Ok(Seconds(d.as_secs())),
+                _ => Err(Error::OutOfRange(format!("{d:?}"))),
+            }
+        }
+    }
+
+    let d = parse_into::<Duration>("1 min");
+    assert_eq!(d, Ok(Duration::from_secs(60)));
+
+    let d = parse_into("1 min 2 s");
+    assert_eq!(d, Ok(Seconds(62)));
+
+    let d = parse_into::<Seconds>("1 s 2 ms");
+    assert_eq!(d, Err(Error::OutOfRange("1.002s".to_owned())));
+
+    let d = parse_into::<Seconds>("1 x");
+    assert_eq!(d, Err(Error::UnexpectedUnit("x".to_owned())));
+}
+
+#[test]
+fn test_parsing_large_numbers() {
+    let d = parse("5000000000 seconds");
+    assert_eq!(d, Ok(Duration::from_secs(5_000_000_000)));
+
+    let d = parse("18446744073709551615 ms");
+    assert_eq!(d, Ok(Duration::from_millis(u64::MAX)));
+
+    let d = parse("18446744073709551616 ms");
+    assert_eq!(d, Err(Error::Overflow));
+
+    let d = parse("18446744073709551615 hours");
+    assert_eq!(d, Err(Error::Overflow));
+
+    let d = parse("18446744073709551615 s 1 s");
+    assert_eq!(d, Err(Error::Overflow));
+}
+
+#[test]
+fn test_parsing_fractions() {
+    let parser = Parser::new(ParserOptions {
+        allow_fractions: true,
+        ..Default::default()
+    });
+
+    let d = parser.parse("1.5 hours");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = parser.parse("0.25s 2.125ms");
+    assert_eq!(d, Ok(Duration::from_micros(252_125)));
+
+    let d = parser.parse("0.0000000015 s");
+    assert_eq!(d, Ok(Duration::from_nanos(1)));
+
+    let d = parser.parse("1. hours");
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+
+    let d = parser.parse("1.5");
+    assert_eq!(d, Err(Error::ExpectedUnit));
+
+    let d = parse("1.5 hours");
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+}
+
+#[test]
+fn test_parsing_min_unit() {
+    let parser = Parser::new(ParserOptions {
+        min_unit: Some(Duration::from_secs(60)),
+        ..Default::default()
+    });
+
+    let d = parser.parse("1h 30m");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = parser.parse("1h 30m 15s");
+    assert_eq!(d, Err(Error::UnitTooSmall("s".to_owned())));
+
+    let d = parser.parse("500 milliseconds");
+    assert_eq!(d, Err(Error::UnitTooSmall("milliseconds".to_owned())));
+
+    let d = parse("500 milliseconds");
+    assert_eq!(d, Ok(Duration::from_millis(500)));
+}
+
+#[test]
+fn test_parsing_case_folding() {
+    let mut units = ParserUnits::default();
+    units.add_unit("dakika", Duration::from_secs(60));
+    units.add_unit("saat", Duration::from_secs(3600));
+    let parser = Parser::new(ParserOptions {
+        units,
+        ignore_case: true,
+        ..Default::default()
+    });
+
+    let d = parser.parse("1 SAAT 30 DAKİKA");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = parser.parse("2 DAKIKA");
+    assert_eq!(d, Ok(Duration::from_secs(120)));
+
+    let d = parser.parse("2 dakıka");
+    assert_eq!(d, Ok(Duration::from_secs(120)));
+
+    let d = parser.parse("2 MİN");
+    assert_eq!(d, Ok(Duration::from_secs(120)));
+}
+
+#[test]
+fn test_parsing_with_warnings() {
+    use durstr::{Warning, parse_with_warnings};
+
+    let mut units = ParserUnits::default();
+    units.add_approximate_unit("month", Duration::from_secs(86400) * 30);
+    let parser = Parser::new(ParserOptions {
+        units,
+        ..Default::default()
+    });
+
+    let d = parser.parse_with_warnings("1 month 2 h");
+    assert_eq!(
+        d,
+        Ok((
+            Duration::from_secs(86400 * 30 + 7200),
+            vec![Warning::ApproximateUnit("month".to_owned())]
+        ))
+    );
+
+    let d = parser.parse_with_warnings("1 month 1 month");
+    assert_eq!(
+        d.unwrap().1,
+        [
+            Warning::ApproximateUnit("month".to_owned()),
+            Warning::ApproximateUnit("month".to_owned()),
+            Warning::RepeatedUnit("month".to_owned()),
+        ]
+    );
+
+    let d = parse_with_warnings("1h 30m 1hr 2 minutes");
+    assert_eq!(
+        d,
+        Ok((
+            Duration::from_secs(9120),
+            vec![
+                Warning::RepeatedUnit("hr".to_owned()),
+                Warning::RepeatedUnit("minutes".to_owned()),
+            ]
+        ))
+    );
+
+    let d = parse_with_warnings("1h 30m");
+    assert_eq!(d, Ok((Duration::from_secs(5400), vec![])));
+
+    let d = parse_with_warnings("1 x");
+    assert_eq!(d, Err(Error::UnexpectedUnit("x".to_owned())));
+}
+
+#[test]
+fn test_parsing_typos() {
+    use durstr::Warning;
+
+    let parser = Parser::new(ParserOptions {
+        correct_typos: true,
+        ..Default::default()
+    });
+
+    let d = parser.parse("2 minutse");
+    assert_eq!(d, Ok(Duration::from_secs(120)));
+
+    let d = parser.parse("1 huor 3 secnds 2 mss");
+    assert_eq!(d, Ok(Duration::from_millis(3_603_002)));
+
+    let d = parser.parse("1 hoursx");
+    assert_eq!(d, Ok(Duration::from_secs(3600)));
+
+    let d = parser.parse_with_warnings("2 minutse 1 s");
+    assert_eq!(
+        d,
+        Ok((
+            Duration::from_secs(121),
+            vec![Warning::CorrectedUnit {
+                found: "minutse".to_owned(),
+                unit: "minutes".to_owned(),
+            }]
+        ))
+    );
+
+    // too short to correct
+    let d = parser.parse("2 x");
+    assert_eq!(d, Err(Error::UnexpectedUnit("x".to_owned())));
+
+    // two edits away
+    let d = parser.parse("2 minnutse");
+    assert_eq!(d, Err(Error::UnexpectedUnit("minnutse".to_owned())));
+
+    // "ms" and "mins" are both one edit away
+    let d = parser.parse("2 mis");
+    assert_eq!(d, Err(Error::UnexpectedUnit("mis".to_owned())));
+
+    let d = parse("2 minutse");
+    assert_eq!(d, Err(Error::UnexpectedUnit("minutse".to_owned())));
+}
+
+#[test]
+fn test_parsing_max_components() {
+    let parser = Parser::new(ParserOptions {
+        max_components: Some(3),
+        ..Default::default()
+    });
+
+    let d = parser.parse("1h 2m 3s");
+    assert_eq!(d, Ok(Duration::from_secs(3723)));
+
+    let d = parser.parse("1h 2m 3s 4ms");
+    assert_eq!(d, Err(Error::TooManyComponents(3)));
+
+    let d = parser.parse(&"1s ".repeat(30));
+    assert_eq!(d, Err(Error::TooManyComponents(3)));
+
+    let d = parse(&"1s ".repeat(30));
+    assert_eq!(d, Ok(Duration::from_secs(30)));
+}
+
+#[test]
+fn test_parsing_reject_zero() {
+    let parser = Parser::new(ParserOptions {
+        reject_zero: true,
+        allow_fractions: true,
+        ..Default::default()
+    });
+
+    let d = parser.parse("1h 0m 30s");
+    assert_eq!(d, Err(Error::ZeroComponent("m".to_owned())));
+
+    let d = parser.parse("1h 0.0 minutes");
+    assert_eq!(d, Err(Error::ZeroComponent("minutes".to_owned())));
+
+    let d = parser.parse("0s");
+    assert_eq!(d, Ok(Duration::ZERO));
+
+    let d = parser.parse("1h 0.5m");
+    assert_eq!(d, Ok(Duration::from_secs(3630)));
+
+    let d = parse("1h 0m 30s");
+    assert_eq!(d, Ok(Duration::from_secs(3630)));
+}
+
+#[test]
+fn test_parsing_default_unit() {
+    let parser = Parser::new(ParserOptions {
+        default_unit: Some("s"),
+        ..Default::default()
+    });
+
+    let d = parser.parse("90");
+    assert_eq!(d, Ok(Duration::from_secs(90)));
+
+    let d = parser.parse("every 5");
+    assert_eq!(d, Ok(Duration::from_secs(5)));
+
+    let d = parser.parse("1m 30");
+    assert_eq!(d, Err(Error::ExpectedUnit));
+
+    let d = parse("90");
+    assert_eq!(d, Err(Error::ExpectedUnit));
+}
+
+#[test]
+fn test_options_from_spec() {
+    let options = ParserOptions::from_spec("ignore_case, units=s|m|h, default_unit=m").unwrap();
+    assert!(options.ignore_case);
+    assert_eq!(options.default_unit, Some("m"));
+    let parser = Parser::new(options);
+
+    let d = parser.parse("2H 5");
+    assert_eq!(d, Err(Error::ExpectedUnit));
+
+    let d = parser.parse("5");
+    assert_eq!(d, Ok(Duration::from_secs(300)));
+
+    let d = parser.parse("5 mins");
+    assert_eq!(d, Err(Error::UnexpectedUnit("mins".to_owned())));
+
+    let options = ParserOptions::from_spec("min_unit=s,max_components=2,reject_zero").unwrap();
+    assert_eq!(options.min_unit, Some(Duration::from_secs(1)));
+    assert_eq!(options.max_components, Some(2));
+    assert!(options.reject_zero);
+
+    let options = ParserOptions::from_spec("");
+    assert!(options.is_ok());
+
+    for (spec, item) in [
+        ("ignore_case,shout", "shout"),
+        ("units=s|days", "units=s|days"),
+        ("units=s,default_unit=m", "default_unit=m"),
+        ("max_components=many", "max_components=many"),
+        ("ignore_case=yes", "ignore_case=yes"),
+    ] {
+        let options = ParserOptions::from_spec(spec);
+        assert_eq!(
+            options.err(),
+            Some(Error::InvalidSpec(item.to_owned())),
+            "{spec}"
+        );
+    }
+}
+
+#[test]
+fn test_options_env_overrides() {
+    // SAFETY: no other test reads or writes this variable
+    unsafe { std::env::set_var("DURSTR_OPTIONS", "ignore_case,units=h") };
+
+    let options = ParserOptions {
+        allow_fractions: true,
+        ..Default::default()
+    };
+    let parser = Parser::new(options.with_env_overrides().unwrap());
+
+    let d = parser.parse("1.5 H");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = parser.parse("1 s");
+    assert_eq!(d, Err(Error::UnexpectedUnit("s".to_owned())));
+
+    unsafe { std::env::remove_var("DURSTR_OPTIONS") };
+}
+
+#[test]
+fn test_parsing_lists() {
+    use durstr::parse_list;
+
+    let d = parse_list("1s, 5s, 30s, 2m");
+    assert_eq!(d, Ok([1, 5, 30, 120].map(Duration::from_secs).to_vec()));
+
+    let d = parse_list("1m 30s,2h");
+    assert_eq!(d, Ok([90, 7200].map(Duration::from_secs).to_vec()));
+
+    let d = parse_list("  ");
+    assert_eq!(d, Ok(vec![]));
+
+    let d = parse_list("1s, , 5s");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parse_list("1s, 5s,");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parse_list("1s, 5x");
+    assert_eq!(d, Err(Error::UnexpectedUnit("x".to_owned())));
+}
+
+#[test]
+fn test_parsing_ticks() {
+    let parser = Parser::new(ParserOptions {
+        tick_rate: Some(20),
+        ..Default::default()
+    });
+
+    let d = parser.parse("6000 ticks");
+    assert_eq!(d, Ok(Duration::from_secs(300)));
+
+    let d = parser.parse("1 tick");
+    assert_eq!(d, Ok(Duration::from_millis(50)));
+
+    let parser = Parser::new(ParserOptions {
+        tick_rate: Some(30),
+        ..Default::default()
+    });
+
+    let d = parser.parse("40 ticks");
+    assert_eq!(d, Ok(Duration::from_nanos(1_333_333_333)));
+
+    let d = parser.parse("3000 ticks 1m");
+    assert_eq!(d, Ok(Duration::from_secs(160)));
+
+    let parser = Parser::new(ParserOptions::from_spec("tick_rate=64").unwrap());
+    let d = parser.parse("30 ticks");
+    assert_eq!(d, Ok(Duration::from_micros(468_750)));
+
+    let d = Parser::default().parse("40 ticks");
+    assert_eq!(d, Err(Error::UnexpectedUnit("ticks".to_owned())));
+}
+
+#[test]
+fn test_parsing_samples() {
+    let parser = Parser::new(ParserOptions {
+        sample_rate: Some(48000),
+        ..Default::default()
+    });
+
+    let d = parser.parse("4800 samples");
+    assert_eq!(d, Ok(Duration::from_millis(100)));
+
+    let d = parser.parse("1 sample");
+    assert_eq!(d, Ok(Duration::from_nanos(20833)));
+
+    let parser = Parser::new(ParserOptions::from_spec("sample_rate=44100").unwrap());
+    let d = parser.parse("44100 samples 1s");
+    assert_eq!(d, Ok(Duration::from_secs(2)));
+
+    let d = parser.parse("441 samples");
+    assert_eq!(d, Ok(Duration::from_millis(10)));
+}
+
+#[test]
+fn test_parsing_clock_ranges() {
+    use durstr::parse_clock_range;
+
+    let d = parse_clock_range("9:00-17:30");
+    assert_eq!(d, Ok(Duration::from_secs(8 * 3600 + 1800)));
+
+    let d = parse_clock_range("22:00 – 6:00");
+    assert_eq!(d, Ok(Duration::from_secs(8 * 3600)));
+
+    let d = parse_clock_range("8:15:30-8:16");
+    assert_eq!(d, Ok(Duration::from_secs(30)));
+
+    let d = parse_clock_range("0:00-24:00");
+    assert_eq!(d, Ok(Duration::from_secs(24 * 3600)));
+
+    let d = parse_clock_range("7:00-7:00");
+    assert_eq!(d, Ok(Duration::from_secs(24 * 3600)));
+
+    let d = parse_clock_range("9:00");
+    assert_eq!(d, Err(Error::ExpectedClockRange));
+
+    let d = parse_clock_range("9:00-25:00");
+    assert_eq!(d, Err(Error::OutOfRange("25".to_owned())));
+
+    let d = parse_clock_range("9:60-10:00");
+    assert_eq!(d, Err(Error::OutOfRange("60".to_owned())));
+}
+
+#[test]
+fn test_parsing_until() {
+    use durstr::parse_until;
+    use std::time::UNIX_EPOCH;
+
+    // 2024-01-01 16:00:30 UTC
+    let now = UNIX_EPOCH + Duration::from_secs(19723 * 86400 + 16 * 3600 + 30);
+
+    let d = parse_until("until 17:30", now);
+    assert_eq!(d, Ok(Duration::from_secs(5370)));
+
+    let d = parse_until("Till Midnight", now);
+    assert_eq!(d, Ok(Duration::from_secs(8 * 3600 - 30)));
+
+    let d = parse_until("until noon", now);
+    assert_eq!(d, Ok(Duration::from_secs(20 * 3600 - 30)));
+
+    // a time that has passed is tomorrow
+    let d = parse_until("til 16:00", now);
+    assert_eq!(d, Ok(Duration::from_secs(24 * 3600 - 30)));
+
+    let d = parse_until("until 16:00:30", now);
+    assert_eq!(d, Ok(Duration::from_secs(24 * 3600)));
+
+    let d = parse_until("17:30", now);
+    assert_eq!(d, Err(Error::ExpectedUntil));
+
+    let d = parse_until("after 17:30", now);
+    assert_eq!(d, Err(Error::ExpectedUntil));
+
+    let d = parse_until("until 25:00", now);
+    assert_eq!(d, Err(Error::OutOfRange("25".to_owned())));
+}
+
+#[test]
+fn test_parsing_maps() {
+    use durstr::parse_map;
+
+    let d = parse_map("connect=5s; read=30s; total=2m");
+    let expected = [("connect", 5), ("read", 30), ("total", 120)];
+    assert_eq!(
+        d,
+        Ok(expected.map(|(k, v)| (k, Duration::from_secs(v))).to_vec())
+    );
+
+    let d = parse_map(" b = 1m 30s;a=1h ");
+    let expected = [("b", 90), ("a", 3600)];
+    assert_eq!(
+        d,
+        Ok(expected.map(|(k, v)| (k, Duration::from_secs(v))).to_vec())
+    );
+
+    let d = parse_map(" ");
+    assert_eq!(d, Ok(vec![]));
+
+    let d = parse_map("connect=5s; read");
+    assert_eq!(d, Err(Error::ExpectedMapEntry("read".to_owned())));
+
+    // a trailing ";" is ignored, but not an empty entry before it
+    let d = parse_map("connect=5s; ");
+    assert_eq!(d, Ok(vec![("connect", Duration::from_secs(5))]));
+
+    let d = parse_map("connect=5s;;");
+    assert_eq!(d, Err(Error::ExpectedMapEntry("".to_owned())));
+
+    let d = parse_map("=5s");
+    assert_eq!(d, Err(Error::ExpectedMapEntry("=5s".to_owned())));
+
+    let d = parse_map("read=5s; read=10s");
+    assert_eq!(d, Err(Error::DuplicateMapEntry("read".to_owned())));
+
+    let d = parse_map("connect=5s; read=");
+    assert_eq!(
+        d,
+        Err(Error::InvalidMapEntry {
+            name: "read".to_owned(),
+            error: Box::new(Error::ExpectedNumber),
+        })
+    );
+
+    let entries = Parser::default().parse_map_entries("a=1x; b; c=2s; c=3s; d=4s;");
+    assert_eq!(
+        entries,
+        [
+            Err(Error::InvalidMapEntry {
+                name: "a".to_owned(),
+                error: Box::new(Error::UnexpectedUnit("x".to_owned())),
+            }),
+            Err(Error::ExpectedMapEntry("b".to_owned())),
+            Ok(("c", Duration::from_secs(2))),
+            Err(Error::DuplicateMapEntry("c".to_owned())),
+            Ok(("d", Duration::from_secs(4))),
+        ]
+    );
+}
+
+#[test]
+fn test_exporting_spec() {
+    let spec = Parser::default().export_spec();
+    let aliases: Vec<_> = spec.units.iter().map(|u| u.aliases.clone()).collect();
+    assert_eq!(
+        aliases,
+        [
+            vec!["h", "hour", "hours", "hr", "hrs"],
+            vec!["m", "min", "mins", "minute", "minutes"],
+            vec!["s", "sec", "second", "seconds", "secs"],
+            vec!["millisecond", "milliseconds", "ms", "msec", "msecs"],
+        ]
+    );
+    assert!(!spec.allow_fractions);
+    assert_eq!(spec.interval_words, ["every", "each"]);
+
+    let mut units = ParserUnits::new();
+    units.add_unit("min", Duration::from_secs(60));
+    units.add_approximate_unit("mo", Duration::from_secs(86400 * 30));
+    units.add_unit("say \"hi\"", Duration::from_millis(1500));
+
+    let parser = Parser::new(ParserOptions {
+        units,
+        ignore_case: true,
+        max_components: Some(3),
+        default_unit: Some("min"),
+        ..Default::default()
+    });
+    assert_eq!(
+        parser.export_spec().to_json(),
+        concat!(
+            r#"{"units":["#,
+            r#"{"aliases":["mo"],"duration":{"secs":2592000,"nanos":0},"approximate":true},"#,
+            r#"{"aliases":["min"],"duration":{"secs":60,"nanos":0},"approximate":false},"#,
+            r#"{"aliases":["say \"hi\""],"duration":{"secs":1,"nanos":500000000},"approximate":false}],"#,
+            r#""separators":[",","،","\u061c","\u200e","\u200f","\u202a","\u202b","\u202c","\u202d","\u202e","#,
+            r#""\u2066","\u2067","\u2068","\u2069"],"decimal_separators":[".","٫"],"abbreviation_marks":["."],"#,
+            r#""interval_words":["every","each"],"#,
+            r#""ignore_case":true,"allow_fractions":false,"correct_typos":false,"reject_zero":false,"#,
+            r#""max_components":3,"min_unit":null,"default_unit":"min","tick_rate":null,"sample_rate":null}"#,
+        )
+    );
+}
+
+#[test]
+fn test_exported_spec_matches_lexer() {
+    use durstr::lexer::{Lexer, TokenKind};
+
+    fn kinds(input: &str) -> Vec<TokenKind<'_>> {
+        Lexer::new(input)
+            .map(|t| t.map(|t| t.kind))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    let spec = Parser::default().export_spec();
+
+    for c in spec.separators {
+        let input = format!("1h{c}2m");
+        let tokens = kinds(&input);
+        assert_eq!(tokens.len(), 4, "{c:?} is not skipped");
+    }
+
+    for c in spec.decimal_separators {
+        let input = format!("1{c}5h");
+        let tokens = kinds(&input);
+        let half = TokenKind::Number(durstr::lexer::Quantity {
+            whole: 1,
+            frac: Some(500_000_000),
+        });
+        assert_eq!(tokens[0], half, "{c:?} is not a decimal point");
+    }
+
+    for c in spec.abbreviation_marks {
+        let input = format!("1min{c} 2s");
+        let tokens = kinds(&input);
+        assert_eq!(tokens[1], TokenKind::Unit("min"), "{c:?} is not skipped");
+        assert_eq!(tokens.len(), 4);
+    }
+
+    // any other punctuation is an error
+    for c in ['.', ';', '/'] {
+        assert!(Lexer::new(&format!("1h {c} 2m")).any(|t| t.is_err()));
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "postgres")]
+
+use std::time::Duration;
+
+use durstr::{
+    Error,
+    postgres::{format_interval, parse_interval},
+};
+
+const DAY: u64 = 86400;
+
+#[test]
+fn test_parsing_intervals() {
+    let d = parse_interval("1 day 02:03:04");
+    assert_eq!(d, Ok(Duration::from_secs(DAY + 7384)));
+
+    let d = parse_interval("3 mons 10 days");
+    assert_eq!(d, Ok(Duration::from_secs(DAY * 100)));
+
+    let d = parse_interval("1 year 2 mons");
+    assert_eq!(d, Ok(Duration::from_secs(DAY * 36525 / 100 + DAY * 60)));
+
+    let d = parse_interval("@ 1 hour 30 mins");
+    assert_eq!(d, Ok(Duration::from_secs(5400)));
+
+    let d = parse_interval("@ 2 days 4 hours 5 mins 6 secs");
+    assert_eq!(d, Ok(Duration::from_secs(DAY * 2 + 14706)));
+
+    // postgres_verbose prints fractional seconds
+    let d = parse_interval("@ 4.5 secs");
+    assert_eq!(d, Ok(Duration::from_millis(4500)));
+
+    let d = parse_interval("@ 1 day 2 hours 3 mins 4.5 secs");
+    assert_eq!(d, Ok(Duration::from_millis((DAY + 7384) * 1000 + 500)));
+
+    let d = parse_interval("00:00:01.5");
+    assert_eq!(d, Ok(Duration::from_millis(1500)));
+
+    let d = parse_interval("36:00:00");
+    assert_eq!(d, Ok(Duration::from_secs(DAY + 43200)));
+
+    let d = parse_interval("1 day +02:00:00");
+    assert_eq!(d, Ok(Duration::from_secs(DAY + 7200)));
+
+    let d = parse_interval("00:00:00");
+    assert_eq!(d, Ok(Duration::ZERO));
+
+    let d = parse_interval("-1 days");
+    assert_eq!(d, Err(Error::UnexpectedChar('-')));
+
+    let d = parse_interval("@ 1 hour ago");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = parse_interval("01:60:00");
+    assert_eq!(d, Err(Error::OutOfRange("60".to_owned())));
+
+    let d = parse_interval("213503982334601 days 5124095576030431:00:00");
+    assert_eq!(d, Err(Error::Overflow));
+
+    let d = parse_interval("5124095576030431:00:00 5124095576030431:00:00");
+    assert_eq!(d, Err(Error::Overflow));
+}
+
+#[test]
+fn test_formatting_intervals() {
+    assert_eq!(format_interval(Duration::ZERO), "00:00:00");
+    assert_eq!(
+        format_interval(Duration::from_secs(DAY + 7384)),
+        "1 day 02:03:04"
+    );
+    assert_eq!(format_interval(Duration::from_secs(DAY * 2)), "2 days");
+    assert_eq!(format_interval(Duration::from_secs(59)), "00:00:59");
+    assert_eq!(
+        format_interval(Duration::new(DAY, 250_000_999)),
+        "1 day 00:00:00.25"
+    );
+
+    for s in ["1 day 02:03:04", "10 days", "23:59:59.999999", "00:00:00"] {
+        assert_eq!(format_interval(parse_interval(s).unwrap()), s);
+    }
+}
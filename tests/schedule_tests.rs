@@ -0,0 +1,98 @@
+#![cfg(feature = "schedule")]
+
+use std::time::Duration;
+
+use durstr::{Error, Parser, ParserOptions, parse_schedule};
+
+#[test]
+fn test_parsing_schedules() {
+    let s = parse_schedule("every 5m for 2h").unwrap();
+    assert_eq!(s.period(), Duration::from_secs(300));
+    assert_eq!(s.span(), Duration::from_secs(7200));
+    assert_eq!(s.offsets().count(), 24);
+    assert_eq!(s.offsets().last(), Some(Duration::from_secs(6900)));
+
+    let s = parse_schedule("90s for 4 min").unwrap();
+    let offsets: Vec<_> = s.into_iter().collect();
+    assert_eq!(offsets, [0, 90, 180].map(Duration::from_secs));
+
+    let s = parse_schedule("each minute for 0s").unwrap();
+    assert_eq!(s.offsets().count(), 0);
+
+    let s = parse_schedule("every 5m");
+    assert_eq!(s, Err(Error::ExpectedScheduleSpan));
+
+    let s = parse_schedule("every 5m for");
+    assert_eq!(s, Err(Error::ExpectedScheduleSpan));
+
+    let s = parse_schedule("every 0m for 1h");
+    assert_eq!(s, Err(Error::ZeroSchedulePeriod));
+
+    let s = parse_schedule("every 5 for 1h");
+    assert_eq!(s, Err(Error::ExpectedUnit));
+
+    let parser = Parser::new(ParserOptions {
+        ignore_case: true,
+        ..Default::default()
+    });
+    let s = parser.parse_schedule("Every 1 Hour For 1 Day");
+    assert_eq!(s, Err(Error::UnexpectedUnit("day".to_owned())));
+
+    let s = parser.parse_schedule("EVERY 1 HOUR FOR 3 HOURS").unwrap();
+    assert_eq!(s.offsets().count(), 3);
+}
+
+#[test]
+fn test_parsing_cumulative() {
+    use durstr::{ParserUnits, parse_cumulative};
+
+    const DAY: u64 = 86400;
+
+    let mut units = ParserUnits::default();
+    for u in ["day", "days"] {
+        units.add_unit(u, Duration::from_secs(DAY));
+    }
+    for u in ["week", "weeks"] {
+        units.add_unit(u, Duration::from_secs(DAY * 7));
+    }
+    let parser = Parser::new(ParserOptions {
+        units,
+        ..Default::default()
+    });
+
+    let c = parser
+        .parse_cumulative("30 minutes a day for 2 weeks")
+        .unwrap();
+    assert_eq!(c.amount(), Duration::from_secs(1800));
+    assert_eq!(c.period(), Duration::from_secs(DAY));
+    assert_eq!(c.span(), Duration::from_secs(DAY * 14));
+    assert_eq!(c.total(), Duration::from_secs(1800 * 14));
+
+    let c = parser
+        .parse_cumulative("1h 30m per week for 3 weeks")
+        .unwrap();
+    assert_eq!(c.total(), Duration::from_secs(5400 * 3));
+
+    let c = parser
+        .parse_cumulative("10m every 2 days for 1 week")
+        .unwrap();
+    assert_eq!(c.total(), Duration::from_secs(2100));
+
+    let c = parse_cumulative("5 min an hour for 90 minutes").unwrap();
+    assert_eq!(c.total(), Duration::from_secs(450));
+
+    let c = parser.parse_cumulative("30 minutes a day");
+    assert_eq!(c, Err(Error::ExpectedScheduleSpan));
+
+    let c = parser.parse_cumulative("30 minutes a day for");
+    assert_eq!(c, Err(Error::ExpectedScheduleSpan));
+
+    let c = parser.parse_cumulative("30 minutes for 2 weeks");
+    assert_eq!(c, Err(Error::ExpectedPeriod));
+
+    let c = parser.parse_cumulative("30 minutes per 0 days for 2 weeks");
+    assert_eq!(c, Err(Error::ZeroSchedulePeriod));
+
+    let c = parser.parse_cumulative("30 minutes a fortnight for 2 weeks");
+    assert_eq!(c, Err(Error::UnexpectedUnit("fortnight".to_owned())));
+}
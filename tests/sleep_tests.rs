@@ -0,0 +1,59 @@
+#![cfg(feature = "sleep")]
+
+use std::time::Duration;
+
+use durstr::{Error, sleep};
+
+#[test]
+fn test_parsing_sleep() {
+    let d = sleep::parse("5");
+    assert_eq!(d, Ok(Duration::from_secs(5)));
+
+    let d = sleep::parse("1.5m");
+    assert_eq!(d, Ok(Duration::from_secs(90)));
+
+    let d = sleep::parse(".25h");
+    assert_eq!(d, Ok(Duration::from_secs(900)));
+
+    let d = sleep::parse("2.");
+    assert_eq!(d, Ok(Duration::from_secs(2)));
+
+    let d = sleep::parse("1d 2h 30");
+    assert_eq!(d, Ok(Duration::from_secs(86400 + 7200 + 30)));
+
+    let d = sleep::parse("0.000000001");
+    assert_eq!(d, Ok(Duration::from_nanos(1)));
+
+    let d = sleep::parse("1m 1m");
+    assert_eq!(d, Ok(Duration::from_secs(120)));
+}
+
+#[test]
+fn test_parsing_sleep_errors() {
+    let d = sleep::parse("");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = sleep::parse("m");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = sleep::parse(".");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = sleep::parse("1 m");
+    assert_eq!(d, Err(Error::ExpectedNumber));
+
+    let d = sleep::parse("5ms");
+    assert_eq!(d, Err(Error::UnexpectedChar('m')));
+
+    let d = sleep::parse("1w");
+    assert_eq!(d, Err(Error::UnexpectedUnit("w".to_owned())));
+
+    let d = sleep::parse("1.2.3");
+    assert_eq!(d, Err(Error::UnexpectedChar('.')));
+
+    let d = sleep::parse("1,5");
+    assert_eq!(d, Err(Error::UnexpectedChar(',')));
+
+    let d = sleep::parse("99999999999999999999");
+    assert_eq!(d, Err(Error::Overflow));
+}